@@ -0,0 +1,122 @@
+//! Closed-state call path benchmark
+//!
+//! Several threads make successful calls through one shared circuit:
+//!
+//! - `mutex_circuit` holds a `Mutex<CircuitBreaker>` for the whole call, the
+//!   only way to share a circuit before `SharedCircuitBreaker`.
+//! - `locked_path` locks the circuit to admit and to complete each call. A
+//!   forced-closed circuit always takes this path.
+//! - `fast_path` reads the state from an atomic and records successes straight
+//!   into storage.
+//!
+//! `AsyncCircuitBreaker` is measured the same way on a single thread.
+//!
+//! Run with `cargo bench --bench call_path --features async`.
+
+use breaker_machines::{
+    BucketedStorage, CircuitBreaker, SharedCircuitBreaker, StorageBackend, SystemClock,
+};
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use std::hint::black_box;
+use std::sync::{Arc, Mutex};
+
+const CALLS_PER_THREAD: usize = 2_000;
+
+/// Lock-free counters without an event log, so only the circuit can contend
+fn storage() -> Arc<dyn StorageBackend> {
+    Arc::new(BucketedStorage::with_options(
+        300,
+        1.0,
+        0,
+        Box::new(SystemClock::new()),
+    ))
+}
+
+fn circuit() -> CircuitBreaker {
+    CircuitBreaker::builder("bench")
+        .storage(storage())
+        .failure_threshold(1_000_000)
+        .build()
+}
+
+fn bench_shared_calls(c: &mut Criterion) {
+    let mut group = c.benchmark_group("closed_calls");
+
+    for threads in [1usize, 4, 8] {
+        group.throughput(Throughput::Elements((threads * CALLS_PER_THREAD) as u64));
+
+        let mutex_circuit = Mutex::new(circuit());
+        group.bench_function(BenchmarkId::new("mutex_circuit", threads), |b| {
+            b.iter(|| {
+                std::thread::scope(|scope| {
+                    for _ in 0..threads {
+                        scope.spawn(|| {
+                            for i in 0..CALLS_PER_THREAD {
+                                let _ = mutex_circuit
+                                    .lock()
+                                    .unwrap_or_else(std::sync::PoisonError::into_inner)
+                                    .call(move || Ok::<_, ()>(black_box(i)));
+                            }
+                        });
+                    }
+                });
+            });
+        });
+
+        let locked = SharedCircuitBreaker::from_circuit(circuit());
+        locked.force_close();
+        let fast = SharedCircuitBreaker::from_circuit(circuit());
+
+        for (label, shared) in [("locked_path", &locked), ("fast_path", &fast)] {
+            group.bench_function(BenchmarkId::new(label, threads), |b| {
+                b.iter(|| {
+                    std::thread::scope(|scope| {
+                        for _ in 0..threads {
+                            scope.spawn(|| {
+                                for i in 0..CALLS_PER_THREAD {
+                                    let _ = shared.call(move || Ok::<_, ()>(black_box(i)));
+                                }
+                            });
+                        }
+                    });
+                });
+            });
+        }
+    }
+
+    group.finish();
+}
+
+#[cfg(feature = "async")]
+fn bench_async_calls(c: &mut Criterion) {
+    use breaker_machines::AsyncCircuitBreaker;
+
+    let mut group = c.benchmark_group("async_closed_calls");
+    group.throughput(Throughput::Elements(CALLS_PER_THREAD as u64));
+
+    let locked = AsyncCircuitBreaker::from_circuit(circuit());
+    locked.force_close();
+    let fast = AsyncCircuitBreaker::from_circuit(circuit());
+
+    for (label, circuit) in [("locked_path", &locked), ("fast_path", &fast)] {
+        group.bench_function(label, |b| {
+            b.iter(|| {
+                pollster::block_on(async {
+                    for i in 0..CALLS_PER_THREAD {
+                        let _ = circuit
+                            .call(|| async move { Ok::<_, ()>(black_box(i)) })
+                            .await;
+                    }
+                });
+            });
+        });
+    }
+
+    group.finish();
+}
+
+#[cfg(not(feature = "async"))]
+fn bench_async_calls(_c: &mut Criterion) {}
+
+criterion_group!(benches, bench_shared_calls, bench_async_calls);
+criterion_main!(benches);
@@ -0,0 +1,151 @@
+//! Storage contention benchmark
+//!
+//! Several threads record into their own circuit at the same time. A single
+//! process-wide lock (the pre-sharding `MemoryStorage` layout, reproduced here
+//! as `GlobalLockStorage`) serializes them; the sharded backends should not.
+//!
+//! Run with `cargo bench --bench storage`.
+
+use breaker_machines::{BucketedStorage, Event, EventKind, MemoryStorage, StorageBackend};
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::sync::RwLock;
+use std::time::Instant;
+
+const OPS_PER_THREAD: usize = 2_000;
+
+/// One lock for every circuit, one owned key per write: the old layout.
+#[derive(Debug)]
+struct GlobalLockStorage {
+    events: RwLock<HashMap<String, Vec<Event>>>,
+    start: Instant,
+}
+
+impl GlobalLockStorage {
+    fn new() -> Self {
+        Self {
+            events: RwLock::new(HashMap::new()),
+            start: Instant::now(),
+        }
+    }
+
+    fn record(&self, circuit_name: &str, kind: EventKind, duration: f64) {
+        let timestamp = self.monotonic_time();
+        let mut events = self
+            .events
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let circuit_events = events.entry(circuit_name.to_string()).or_default();
+        circuit_events.push(Event {
+            kind,
+            timestamp,
+            duration,
+        });
+        if circuit_events.len() > 1000 {
+            circuit_events.drain(0..100);
+        }
+    }
+
+    fn count(&self, circuit_name: &str, kind: EventKind, window_seconds: f64) -> usize {
+        let cutoff = self.monotonic_time() - window_seconds;
+        self.events
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .get(circuit_name)
+            .map(|ev| {
+                ev.iter()
+                    .filter(|e| e.kind == kind && e.timestamp >= cutoff)
+                    .count()
+            })
+            .unwrap_or(0)
+    }
+}
+
+impl StorageBackend for GlobalLockStorage {
+    fn record_success(&self, circuit_name: &str, duration: f64) {
+        self.record(circuit_name, EventKind::Success, duration);
+    }
+
+    fn record_failure(&self, circuit_name: &str, duration: f64) {
+        self.record(circuit_name, EventKind::Failure, duration);
+    }
+
+    fn success_count(&self, circuit_name: &str, window_seconds: f64) -> usize {
+        self.count(circuit_name, EventKind::Success, window_seconds)
+    }
+
+    fn failure_count(&self, circuit_name: &str, window_seconds: f64) -> usize {
+        self.count(circuit_name, EventKind::Failure, window_seconds)
+    }
+
+    fn clear(&self, circuit_name: &str) {
+        self.events
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .remove(circuit_name);
+    }
+
+    fn clear_all(&self) {
+        self.events
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clear();
+    }
+
+    fn event_log(&self, _circuit_name: &str, _limit: usize) -> Vec<Event> {
+        Vec::new()
+    }
+
+    fn monotonic_time(&self) -> f64 {
+        self.start.elapsed().as_secs_f64()
+    }
+}
+
+/// Each thread records into its own circuit. Counting is left out on purpose:
+/// `MemoryStorage` counts by scanning, which would hide the locking cost.
+fn hammer(storage: &dyn StorageBackend, names: &[String]) {
+    std::thread::scope(|scope| {
+        for name in names {
+            scope.spawn(move || {
+                for _ in 0..OPS_PER_THREAD {
+                    storage.record_failure(black_box(name), 0.001);
+                }
+            });
+        }
+    });
+}
+
+fn bench_distinct_circuits(c: &mut Criterion) {
+    let mut group = c.benchmark_group("record_distinct_circuits");
+
+    for threads in [1usize, 4, 8] {
+        let names: Vec<String> = (0..threads).map(|t| format!("circuit_{t}")).collect();
+        group.throughput(Throughput::Elements((threads * OPS_PER_THREAD) as u64));
+
+        let backends: [(&str, Box<dyn StorageBackend>); 3] = [
+            ("global_lock", Box::new(GlobalLockStorage::new())),
+            ("memory_sharded", Box::new(MemoryStorage::new())),
+            (
+                "bucketed",
+                Box::new(BucketedStorage::with_options(
+                    300,
+                    1.0,
+                    0,
+                    Box::new(breaker_machines::SystemClock::new()),
+                )),
+            ),
+        ];
+
+        for (label, storage) in &backends {
+            group.bench_with_input(BenchmarkId::new(*label, threads), &names, |b, names| {
+                b.iter(|| hammer(storage.as_ref(), names));
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_distinct_circuits);
+criterion_main!(benches);
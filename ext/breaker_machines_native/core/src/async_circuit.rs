@@ -1,17 +1,26 @@
 //! Async-friendly circuit breaker wrapper.
 //!
 //! This module keeps the core state machine synchronous and runs only short
-//! state checks under a mutex. User futures are awaited outside the lock.
+//! state checks under a mutex. User futures are awaited outside the lock, and
+//! so are [`AsyncStorageBackend`] operations: their results are loaded into a
+//! synchronous mirror the state machine reads under the lock.
 
 use crate::{
-    CircuitBreaker, CircuitBuilder, Config, FallbackContext,
-    circuit::{CallGate, CallPermit},
-    errors::CircuitError,
+    AsyncStorageBackend, CallCounts, CascadeTarget, CircuitBreaker, CircuitBuilder,
+    CircuitOverride, CircuitStatus, Config, Event, FallbackContext, FallbackReason, SlidingWindow,
+    StorageBackend,
+    circuit::{CallGate, CallPermit, FallbackDispatch, FallbackTriggers, FastPath},
+    errors::{CircuitError, StorageError},
+    shared_circuit::CircuitGuard,
+    timer,
 };
 use std::{
     future::Future,
     pin::Pin,
-    sync::{Mutex, MutexGuard},
+    sync::{Arc, Mutex, MutexGuard},
+    task::Poll,
+    time::Duration,
+    vec::Vec,
 };
 
 type BoxFutureResult<T, E> = Pin<Box<dyn Future<Output = Result<T, E>> + Send>>;
@@ -19,12 +28,20 @@ type AsyncFallbackFn<T, E> = Box<dyn FnOnce(FallbackContext) -> BoxFutureResult<
 
 /// Options for async circuit breaker calls.
 pub struct AsyncCallOptions<T, E> {
-    fallback: Option<AsyncFallbackFn<T, E>>,
+    fallbacks: Vec<AsyncFallbackFn<T, E>>,
+    fallback_on: FallbackTriggers,
+    race: bool,
+    timeout_secs: Option<f64>,
 }
 
 impl<T, E> Default for AsyncCallOptions<T, E> {
     fn default() -> Self {
-        Self { fallback: None }
+        Self {
+            fallbacks: Vec::new(),
+            fallback_on: FallbackTriggers::default(),
+            race: false,
+            timeout_secs: None,
+        }
     }
 }
 
@@ -34,13 +51,157 @@ impl<T, E> AsyncCallOptions<T, E> {
         Self::default()
     }
 
-    /// Set an async fallback function called when the circuit is open.
+    /// Add an async fallback function.
+    ///
+    /// Fallbacks run in the order they were added until one succeeds. If all
+    /// of them fail, the call returns the error of the last one.
     pub fn with_fallback<F, Fut>(mut self, fallback: F) -> Self
     where
         F: FnOnce(FallbackContext) -> Fut + Send + 'static,
         Fut: Future<Output = Result<T, E>> + Send + 'static,
     {
-        self.fallback = Some(Box::new(move |ctx| Box::pin(fallback(ctx))));
+        self.fallbacks
+            .push(Box::new(move |ctx| Box::pin(fallback(ctx))));
+        self
+    }
+
+    /// Also run the fallbacks for `reason`, not only when the circuit is open.
+    pub fn fallback_on(mut self, reason: FallbackReason) -> Self {
+        self.fallback_on.insert(reason);
+        self
+    }
+
+    /// Start all fallbacks at once and return the first success.
+    ///
+    /// The fallbacks still running are dropped. If all of them fail, the call
+    /// returns the error of the last one to finish.
+    pub fn race_fallbacks(mut self) -> Self {
+        self.race = true;
+        self
+    }
+
+    /// Give up on the operation after `seconds`, overriding the circuit's
+    /// `call_timeout_secs`.
+    pub fn with_timeout_secs(mut self, seconds: f64) -> Self {
+        self.timeout_secs = Some(seconds);
+        self
+    }
+
+    /// Return `result`, or the fallbacks' answer if they handle its error
+    ///
+    /// See [`FallbackTriggers::dispatch`] for `context` and `fallback_context`.
+    async fn finish(
+        self,
+        result: Result<T, CircuitError<E>>,
+        context: Option<FallbackContext>,
+        fallback_context: impl FnOnce(FallbackReason) -> FallbackContext,
+    ) -> Result<T, CircuitError<E>> {
+        let has_fallbacks = !self.fallbacks.is_empty();
+        match self
+            .fallback_on
+            .dispatch(has_fallbacks, result, context, fallback_context)
+        {
+            FallbackDispatch::Return(result) => result,
+            FallbackDispatch::Fallback { context, error } => {
+                self.run_fallbacks(context, error).await
+            }
+        }
+    }
+
+    /// Run the fallbacks, in order or racing, in place of `error`
+    async fn run_fallbacks(
+        self,
+        context: FallbackContext,
+        error: CircuitError<E>,
+    ) -> Result<T, CircuitError<E>> {
+        let mut last_error = None;
+
+        if self.race {
+            let mut racing: Vec<_> = self
+                .fallbacks
+                .into_iter()
+                .map(|fallback| Some(fallback(context.clone())))
+                .collect();
+            let value = std::future::poll_fn(|cx| {
+                for slot in &mut racing {
+                    let Some(fallback) = slot else {
+                        continue;
+                    };
+                    let Poll::Ready(result) = fallback.as_mut().poll(cx) else {
+                        continue;
+                    };
+                    *slot = None;
+                    match result {
+                        Ok(value) => return Poll::Ready(Some(value)),
+                        Err(error) => last_error = Some(error),
+                    }
+                }
+                if racing.iter().all(Option::is_none) {
+                    Poll::Ready(None)
+                } else {
+                    Poll::Pending
+                }
+            })
+            .await;
+            if let Some(value) = value {
+                return Ok(value);
+            }
+        } else {
+            for fallback in self.fallbacks {
+                match fallback(context.clone()).await {
+                    Ok(value) => return Ok(value),
+                    Err(error) => last_error = Some(error),
+                }
+            }
+        }
+
+        Err(last_error.map_or(error, CircuitError::Execution))
+    }
+}
+
+/// Options for hedged calls, see [`AsyncCircuitBreaker::call_hedged`].
+///
+/// Hedging delays are awaited through `sleep`, typically the runtime's sleep
+/// function such as `tokio::time::sleep`, so they follow the runtime's clock
+/// (including paused time in tests) rather than the crate's timeout timer.
+pub struct HedgeOptions<S> {
+    sleep: S,
+    max_requests: usize,
+    delay_secs: f64,
+}
+
+impl<S> core::fmt::Debug for HedgeOptions<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("HedgeOptions")
+            .field("max_requests", &self.max_requests)
+            .field("delay_secs", &self.delay_secs)
+            .finish()
+    }
+}
+
+impl<S, SFut> HedgeOptions<S>
+where
+    S: Fn(Duration) -> SFut,
+    SFut: Future<Output = ()>,
+{
+    /// Create hedge options with up to 2 attempts, 50ms apart.
+    pub fn new(sleep: S) -> Self {
+        Self {
+            sleep,
+            max_requests: 2,
+            delay_secs: 0.05,
+        }
+    }
+
+    /// Set the maximum number of attempts, including the first (at least 1).
+    pub fn max_requests(mut self, max_requests: usize) -> Self {
+        self.max_requests = max_requests.max(1);
+        self
+    }
+
+    /// Set the delay in seconds before each further attempt starts.
+    pub fn delay_secs(mut self, seconds: f64) -> Self {
+        self.delay_secs = seconds.max(0.0);
         self
     }
 }
@@ -80,12 +241,190 @@ impl Drop for HalfOpenProbe<'_> {
     }
 }
 
+/// Synchronous view of an [`AsyncStorageBackend`] for the state machine.
+///
+/// The async circuit awaits the backend before taking the circuit lock and
+/// loads the results here. Writes the state machine makes (status, clear) are
+/// queued and flushed to the backend once the lock is released.
+#[derive(Debug)]
+pub(crate) struct StorageMirror {
+    backend: Arc<dyn AsyncStorageBackend>,
+    circuit_name: String,
+    state: Mutex<MirrorState>,
+}
+
+#[derive(Debug)]
+struct MirrorState {
+    counts: CallCounts,
+    /// Result of the last awaited record/count
+    recorded: Result<(), StorageError>,
+    /// Result of the last awaited status read
+    status: Result<Option<CircuitStatus>, StorageError>,
+    status_loaded: bool,
+    pending_status: Option<CircuitStatus>,
+    pending_clear: bool,
+}
+
+impl StorageMirror {
+    pub(crate) fn new(backend: Arc<dyn AsyncStorageBackend>, circuit_name: String) -> Self {
+        Self {
+            backend,
+            circuit_name,
+            state: Mutex::new(MirrorState {
+                counts: CallCounts::default(),
+                recorded: Ok(()),
+                status: Ok(None),
+                status_loaded: false,
+                pending_status: None,
+                pending_clear: false,
+            }),
+        }
+    }
+
+    fn lock_state(&self) -> MutexGuard<'_, MirrorState> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Whether the status must be read before the next call
+    fn needs_status(&self) -> bool {
+        self.backend.shares_state() || !self.lock_state().status_loaded
+    }
+
+    fn load_status(&self, status: Result<Option<CircuitStatus>, StorageError>) {
+        self.lock_state().status = status;
+    }
+
+    fn load_counts(&self, counts: Result<CallCounts, StorageError>) {
+        let mut state = self.lock_state();
+        match counts {
+            Ok(counts) => {
+                state.counts = counts;
+                state.recorded = Ok(());
+            }
+            Err(error) => {
+                state.counts = CallCounts::default();
+                state.recorded = Err(error);
+            }
+        }
+    }
+
+    fn take_pending(&self) -> (bool, Option<CircuitStatus>) {
+        let mut state = self.lock_state();
+        (
+            core::mem::take(&mut state.pending_clear),
+            state.pending_status.take(),
+        )
+    }
+}
+
+impl StorageBackend for StorageMirror {
+    // Recording happens on the async backend before the circuit sees the outcome
+    fn record_success(&self, _circuit_name: &str, _duration: f64) {}
+
+    fn record_failure(&self, _circuit_name: &str, _duration: f64) {}
+
+    fn success_count(&self, _circuit_name: &str, _window_seconds: f64) -> usize {
+        self.lock_state().counts.successes
+    }
+
+    fn failure_count(&self, _circuit_name: &str, _window_seconds: f64) -> usize {
+        self.lock_state().counts.failures
+    }
+
+    fn slow_call_count(&self, _circuit_name: &str, _window_seconds: f64) -> usize {
+        self.lock_state().counts.slow_calls
+    }
+
+    fn recent_calls(
+        &self,
+        _circuit_name: &str,
+        _calls: usize,
+        _slow_call_secs: Option<f64>,
+    ) -> CallCounts {
+        self.lock_state().counts
+    }
+
+    fn clear(&self, _circuit_name: &str) {
+        let mut state = self.lock_state();
+        state.counts = CallCounts::default();
+        state.pending_clear = true;
+    }
+
+    fn clear_all(&self) {
+        self.clear(&self.circuit_name);
+    }
+
+    fn event_log(&self, _circuit_name: &str, _limit: usize) -> Vec<Event> {
+        Vec::new()
+    }
+
+    fn monotonic_time(&self) -> f64 {
+        self.backend.monotonic_time()
+    }
+
+    fn get_status(&self, _circuit_name: &str) -> Option<CircuitStatus> {
+        self.lock_state().status.clone().ok().flatten()
+    }
+
+    fn set_status(&self, _circuit_name: &str, status: CircuitStatus) {
+        let mut state = self.lock_state();
+        state.status = Ok(Some(status));
+        state.pending_status = Some(status);
+    }
+
+    /// Until the first status is adopted the mirror reports shared state, so
+    /// the circuit adopts whatever the backend had persisted.
+    fn shares_state(&self) -> bool {
+        self.needs_status()
+    }
+
+    fn try_record_success(&self, _circuit_name: &str, _duration: f64) -> Result<(), StorageError> {
+        self.lock_state().recorded.clone()
+    }
+
+    fn try_record_failure(&self, _circuit_name: &str, _duration: f64) -> Result<(), StorageError> {
+        self.lock_state().recorded.clone()
+    }
+
+    fn try_record_slow_call(
+        &self,
+        _circuit_name: &str,
+        _duration: f64,
+    ) -> Result<(), StorageError> {
+        self.lock_state().recorded.clone()
+    }
+
+    /// Marks the status as adopted: the circuit only calls this once it has
+    /// awaited a fresh read.
+    fn try_get_status(&self, _circuit_name: &str) -> Result<Option<CircuitStatus>, StorageError> {
+        let mut state = self.lock_state();
+        if state.status.is_ok() {
+            state.status_loaded = true;
+        }
+        state.status.clone()
+    }
+}
+
 /// Async-friendly circuit breaker.
 ///
 /// `AsyncCircuitBreaker` can be shared across tasks with `Arc`. It does not
 /// hold its internal mutex while awaiting the protected operation.
+///
+/// Built with [`CircuitBuilder::async_storage`], it records outcomes and reads
+/// counts through an [`AsyncStorageBackend`], again without holding the
+/// mutex across awaits. In that mode the manual `record_*` methods only drive
+/// state transitions; counts are recorded by [`call`](Self::call).
+///
+/// Otherwise, successful calls to a Closed circuit skip the mutex entirely and
+/// are recorded straight into storage, as with
+/// [`SharedCircuitBreaker`](crate::SharedCircuitBreaker).
 pub struct AsyncCircuitBreaker {
     inner: Mutex<CircuitBreaker>,
+    fast: FastPath,
+    storage: Option<Arc<StorageMirror>>,
+    call_timeout_secs: Option<f64>,
 }
 
 impl AsyncCircuitBreaker {
@@ -102,7 +441,20 @@ impl AsyncCircuitBreaker {
     /// Wrap an existing synchronous circuit breaker.
     pub fn from_circuit(circuit: CircuitBreaker) -> Self {
         Self {
+            fast: FastPath::new(&circuit),
+            call_timeout_secs: circuit.config().call_timeout_secs,
             inner: Mutex::new(circuit),
+            storage: None,
+        }
+    }
+
+    /// Wrap a circuit whose storage is `mirror` (used by the builder)
+    pub(crate) fn with_storage_mirror(circuit: CircuitBreaker, mirror: Arc<StorageMirror>) -> Self {
+        Self {
+            fast: FastPath::new(&circuit),
+            call_timeout_secs: circuit.config().call_timeout_secs,
+            inner: Mutex::new(circuit),
+            storage: Some(mirror),
         }
     }
 
@@ -124,6 +476,11 @@ impl AsyncCircuitBreaker {
     }
 
     /// Execute an async fallible operation with async call options.
+    ///
+    /// With a call timeout, an operation still pending when it elapses is
+    /// dropped and recorded as a failure; the call returns
+    /// [`CircuitError::Timeout`]. Failure classifiers see the timeout as a
+    /// [`CallTimeout`](crate::CallTimeout).
     pub async fn call_with_options<F, Fut, T, E: 'static>(
         &self,
         operation: F,
@@ -133,9 +490,39 @@ impl AsyncCircuitBreaker {
         F: FnOnce() -> Fut,
         Fut: Future<Output = Result<T, E>>,
     {
+        let timeout_secs = options.timeout_secs.or(self.call_timeout_secs);
+        let (result, context) = self.call_protected(operation, timeout_secs).await;
+
+        options
+            .finish(result, context, |reason| {
+                self.lock_inner().fallback_context(reason)
+            })
+            .await
+    }
+
+    /// Run a call through the circuit; an Open circuit hands back the context
+    /// for its fallbacks
+    async fn call_protected<F, Fut, T, E: 'static>(
+        &self,
+        operation: F,
+        timeout_secs: Option<f64>,
+    ) -> (Result<T, CircuitError<E>>, Option<FallbackContext>)
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        match &self.storage {
+            Some(mirror) => self.refresh_status(mirror).await,
+            // The mirror only learns of outcomes through `settle`
+            None if self.fast.enabled() => {
+                return (self.call_fast(operation, timeout_secs).await, None);
+            }
+            None => {}
+        }
+
         let gate = {
             let mut circuit = self.lock_inner();
-            match circuit.prepare_call()? {
+            circuit.prepare_call().map(|gate| match gate {
                 CallGate::Execute(permit) => AsyncCallGate::Execute {
                     start: circuit.start_time(),
                     probe: HalfOpenProbe::new(self, permit.half_open_probe()),
@@ -145,7 +532,13 @@ impl AsyncCircuitBreaker {
                     _permit: permit,
                     context,
                 } => AsyncCallGate::Open { permit, context },
-            }
+            })
+        };
+        self.flush_storage().await;
+
+        let gate = match gate {
+            Ok(gate) => gate,
+            Err(error) => return (Err(error), None),
         };
 
         match gate {
@@ -155,27 +548,284 @@ impl AsyncCircuitBreaker {
                 mut probe,
             } => {
                 let half_open_probe = permit.half_open_probe();
-                let result = operation().await;
-                let output = {
-                    let mut circuit = self.lock_inner();
-                    circuit.complete_call(start, result, half_open_probe)
+                let output = match timer::within(timeout_secs, operation()).await {
+                    Ok(result) => self.complete(start, result, half_open_probe).await,
+                    Err(timeout) => self
+                        .complete(start, Err(timeout), half_open_probe)
+                        .await
+                        .map_err(|_| timeout.into_error(self.name())),
                 };
                 probe.disarm();
                 drop(permit);
-                output
+                self.flush_storage().await;
+                (output, None)
             }
             AsyncCallGate::Open { permit, context } => {
                 drop(permit);
+                (Err(context.clone().into_open_error()), Some(context))
+            }
+        }
+    }
+
+    /// Execute an async operation as a hedged request.
+    ///
+    /// The first attempt starts right away. Each further attempt, up to
+    /// `max_requests`, starts once the hedging delay passes without a success,
+    /// or as soon as every running attempt has failed. The first success is
+    /// returned and the attempts still running are dropped. `operation`
+    /// receives the attempt index, so attempts can go to different backends.
+    ///
+    /// Every attempt is a call through the circuit: it takes its own bulkhead
+    /// permit or HalfOpen probe slot, and its outcome is recorded when it
+    /// completes. Dropped attempts release their permit and record nothing.
+    /// Once the circuit rejects an attempt no further attempts start. If every
+    /// attempt fails, the error of the earliest one is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use breaker_machines::{AsyncCircuitBreaker, HedgeOptions};
+    ///
+    /// # async fn sleep(_: std::time::Duration) {}
+    /// # pollster::block_on(async {
+    /// let circuit = AsyncCircuitBreaker::builder("reads").build_async();
+    /// let replicas = ["replica-a", "replica-b", "replica-c"];
+    ///
+    /// let result = circuit
+    ///     .call_hedged(
+    ///         HedgeOptions::new(sleep).max_requests(3).delay_secs(0.02),
+    ///         |attempt| async move { Ok::<_, String>(replicas[attempt]) },
+    ///     )
+    ///     .await;
+    /// assert_eq!(result.unwrap(), "replica-a");
+    /// # });
+    /// ```
+    pub async fn call_hedged<F, Fut, S, SFut, T, E: 'static>(
+        &self,
+        options: HedgeOptions<S>,
+        mut operation: F,
+    ) -> Result<T, CircuitError<E>>
+    where
+        F: FnMut(usize) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        S: Fn(Duration) -> SFut,
+        SFut: Future<Output = ()>,
+    {
+        let delay = Duration::try_from_secs_f64(options.delay_secs).unwrap_or(Duration::MAX);
+        let mut attempts = Vec::new();
+        let mut timer: Option<Pin<Box<SFut>>> = None;
+        let mut launched = 0;
+        let mut rejected = false;
+        let mut earliest_error: Option<(usize, CircuitError<E>)> = None;
 
-                if let Some(fallback) = options.fallback {
-                    return fallback(context).await.map_err(CircuitError::Execution);
+        std::future::poll_fn(|cx| {
+            loop {
+                let running = attempts.iter().any(Option::is_some);
+                let exhausted = launched == options.max_requests || rejected;
+                if !running
+                    && exhausted
+                    && let Some((_, error)) = earliest_error.take()
+                {
+                    return Poll::Ready(Err(error));
                 }
 
-                Err(CircuitError::Open {
-                    circuit: context.circuit_name,
-                    opened_at: context.opened_at,
-                })
+                let due = !exhausted
+                    && (!running
+                        || timer
+                            .as_mut()
+                            .is_some_and(|timer| timer.as_mut().poll(cx).is_ready()));
+                if due {
+                    let attempt = operation(launched);
+                    attempts.push(Some(Box::pin(self.call(move || attempt))));
+                    launched += 1;
+                    timer = Some(Box::pin((options.sleep)(delay)));
+                }
+
+                let mut settled = false;
+                for (index, slot) in attempts.iter_mut().enumerate() {
+                    let Some(attempt) = slot else {
+                        continue;
+                    };
+                    let Poll::Ready(result) = attempt.as_mut().poll(cx) else {
+                        continue;
+                    };
+                    *slot = None;
+                    settled = true;
+                    match result {
+                        Ok(value) => return Poll::Ready(Ok(value)),
+                        Err(error) => {
+                            rejected |= !matches!(
+                                error,
+                                CircuitError::Execution(_) | CircuitError::Timeout { .. }
+                            );
+                            if earliest_error
+                                .as_ref()
+                                .is_none_or(|(first, _)| index < *first)
+                            {
+                                earliest_error = Some((index, error));
+                            }
+                        }
+                    }
+                }
+
+                if !due && !settled {
+                    return Poll::Pending;
+                }
+            }
+        })
+        .await
+    }
+
+    /// Run a call admitted by the fast path; only failures and slow calls lock
+    async fn call_fast<F, Fut, T, E: 'static>(
+        &self,
+        operation: F,
+        timeout_secs: Option<f64>,
+    ) -> Result<T, CircuitError<E>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let start = self.fast.start_time();
+        let result = match timer::within(timeout_secs, operation()).await {
+            Ok(result) => result,
+            Err(timeout) => {
+                let mut circuit = self.lock_inner();
+                return circuit
+                    .complete_call(start, Err(timeout), false)
+                    .map_err(|_| timeout.into_error(String::from(circuit.name())));
+            }
+        };
+
+        if result.is_ok()
+            && let Some(recorded) = self.fast.record_success(start)
+        {
+            if let Err(error) = recorded {
+                self.lock_inner().track_storage(Err::<(), _>(error));
             }
+            return result.map_err(CircuitError::Execution);
+        }
+
+        self.lock_inner().complete_call(start, result, false)
+    }
+
+    /// Record the outcome of an admitted call, in async storage if there is one
+    async fn complete<T, E: 'static>(
+        &self,
+        start: f64,
+        result: Result<T, E>,
+        half_open_probe: bool,
+    ) -> Result<T, CircuitError<E>> {
+        match &self.storage {
+            Some(mirror) => self.settle(mirror, start, result, half_open_probe).await,
+            None => self
+                .lock_inner()
+                .complete_call(start, result, half_open_probe),
+        }
+    }
+
+    /// Record the outcome in async storage, then let the circuit act on it.
+    ///
+    /// Nothing is awaited after the circuit completes the call, so the caller
+    /// can disarm the half-open probe guard right after.
+    async fn settle<T, E: 'static>(
+        &self,
+        mirror: &StorageMirror,
+        start: f64,
+        result: Result<T, E>,
+        half_open_probe: bool,
+    ) -> Result<T, CircuitError<E>> {
+        let backend = &mirror.backend;
+        let name = mirror.circuit_name.as_str();
+        let duration = backend.monotonic_time() - start;
+
+        let (should_trip, slow, config, disabled) = {
+            let circuit = self.lock_inner();
+            let should_trip = match &result {
+                Ok(_) => false,
+                Err(e) => circuit.should_trip_on(e, duration),
+            };
+            (
+                should_trip,
+                circuit.is_slow(duration),
+                circuit.config().clone(),
+                circuit.is_disabled(),
+            )
+        };
+        let count_slow = config.slow_call_rate_threshold.is_some();
+        let count_successes = config.failure_rate_threshold.is_some() || count_slow;
+        let window = config.failure_window_secs;
+
+        let counts = match &result {
+            _ if disabled => None,
+            Err(_) if !should_trip => None,
+            _ => Some(
+                async {
+                    match &result {
+                        Ok(_) => backend.record_success(name, duration).await?,
+                        Err(_) => backend.record_failure(name, duration).await?,
+                    }
+                    if slow {
+                        backend.record_slow_call(name, duration).await?;
+                    }
+
+                    // Counts are only read when the outcome can trip the circuit
+                    let mut counts = CallCounts::default();
+                    if !(should_trip || slow && count_slow) {
+                        return Ok(counts);
+                    }
+                    if let SlidingWindow::Count(calls) = config.sliding_window {
+                        return backend
+                            .recent_calls(name, calls, config.slow_call_duration_secs)
+                            .await;
+                    }
+                    counts.failures = backend.failure_count(name, window).await?;
+                    if count_successes {
+                        counts.successes = backend.success_count(name, window).await?;
+                    }
+                    if count_slow {
+                        counts.slow_calls = backend.slow_call_count(name, window).await?;
+                    }
+                    Ok(counts)
+                }
+                .await,
+            ),
+        };
+
+        let mut circuit = self.lock_inner();
+        if let Some(counts) = counts {
+            mirror.load_counts(counts);
+        }
+        circuit.complete_classified_call(duration, result, should_trip, half_open_probe)
+    }
+
+    /// Read the persisted status before the first call, before every call
+    /// when the backend shares state, and while the backend is failing.
+    async fn refresh_status(&self, mirror: &StorageMirror) {
+        let stale = mirror.needs_status() || self.lock_inner().storage_error().is_some();
+        if stale {
+            let status = mirror.backend.get_status(&mirror.circuit_name).await;
+            mirror.load_status(status);
+        }
+    }
+
+    /// Apply status changes and clears queued by the state machine
+    async fn flush_storage(&self) {
+        let Some(mirror) = &self.storage else {
+            return;
+        };
+
+        let (clear, status) = mirror.take_pending();
+        if clear {
+            let result = mirror.backend.clear(&mirror.circuit_name).await;
+            self.lock_inner().track_storage(result);
+        }
+        if let Some(status) = status {
+            let result = mirror
+                .backend
+                .set_status(&mirror.circuit_name, status)
+                .await;
+            self.lock_inner().track_storage(result);
         }
     }
 
@@ -204,30 +854,87 @@ impl AsyncCircuitBreaker {
         self.lock_inner().check_and_trip()
     }
 
+    /// Name the circuit was created with.
+    pub fn name(&self) -> String {
+        String::from(self.lock_inner().name())
+    }
+
     /// Check if circuit is open.
     pub fn is_open(&self) -> bool {
         self.lock_inner().is_open()
     }
 
+    /// Seconds until an Open circuit lets a probe through, or None if it isn't Open.
+    pub fn retry_after(&self) -> Option<f64> {
+        self.lock_inner().retry_after()
+    }
+
     /// Check if circuit is closed.
     pub fn is_closed(&self) -> bool {
         self.lock_inner().is_closed()
     }
 
-    /// Get current state name.
+    /// Get current state name, or the name of the override if one is set.
     pub fn state_name(&self) -> &'static str {
         self.lock_inner().state_name()
     }
 
+    /// Operator override currently in effect.
+    pub fn override_mode(&self) -> Option<CircuitOverride> {
+        self.lock_inner().override_mode()
+    }
+
+    /// Handle for parent circuits to force this one open.
+    pub fn cascade_target(&self) -> CascadeTarget {
+        self.lock_inner().cascade_target()
+    }
+
+    /// Name of the parent circuit whose cascade holds this circuit open.
+    pub fn cascade_source(&self) -> Option<String> {
+        self.lock_inner().cascade_source()
+    }
+
+    /// Drop every parent's cascade latch.
+    pub fn clear_cascade(&self) {
+        self.lock_inner().clear_cascade();
+    }
+
+    /// Open the circuit and reject every call until the override is released.
+    pub fn force_open(&self) {
+        self.lock_inner().force_open();
+    }
+
+    /// Close the circuit and keep it closed, whatever the thresholds say.
+    pub fn force_close(&self) {
+        self.lock_inner().force_close();
+    }
+
+    /// Close the circuit and pass calls straight through, unrecorded.
+    pub fn disable(&self) {
+        self.lock_inner().disable();
+    }
+
+    /// Lift the override and let the thresholds drive the circuit again.
+    pub fn release_override(&self) {
+        self.lock_inner().release_override();
+    }
+
     /// Clear all events and reset circuit to Closed state.
+    ///
+    /// With async storage the backend is cleared on the next call; use
+    /// [`reset_async`](Self::reset_async) to clear it right away.
     pub fn reset(&self) {
         self.lock_inner().reset();
     }
 
-    fn lock_inner(&self) -> MutexGuard<'_, CircuitBreaker> {
-        self.inner
-            .lock()
-            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    /// Reset the circuit and wait until async storage has been cleared.
+    pub async fn reset_async(&self) {
+        self.reset();
+        self.flush_storage().await;
+    }
+
+    fn lock_inner(&self) -> CircuitGuard<'_> {
+        CircuitGuard::lock(&self.inner, &self.fast)
     }
 }
 
@@ -296,6 +1003,62 @@ mod tests {
         });
     }
 
+    #[test]
+    fn async_fallbacks_race_for_the_first_success() {
+        let circuit = AsyncCircuitBreaker::builder("test").build_async();
+        circuit.force_open();
+
+        let result = pollster::block_on(
+            circuit.call_with_options(
+                || async { Ok::<_, &str>("should not execute") },
+                AsyncCallOptions::new()
+                    .with_fallback(|_ctx| std::future::pending())
+                    .with_fallback(|_ctx| async { Err("cache miss") })
+                    .with_fallback(|_ctx| async { Ok("replica") })
+                    .race_fallbacks(),
+            ),
+        );
+        assert_eq!(result.unwrap(), "replica");
+
+        // In order, a hanging fallback blocks the ones after it
+        let mut chained = Box::pin(
+            circuit.call_with_options(
+                || async { Ok::<_, &str>("should not execute") },
+                AsyncCallOptions::new()
+                    .with_fallback(|_ctx| std::future::pending())
+                    .with_fallback(|_ctx| async { Ok("replica") }),
+            ),
+        );
+        assert!(poll_once(chained.as_mut()).is_pending());
+    }
+
+    #[test]
+    fn async_fallback_on_half_open_limit() {
+        let circuit = AsyncCircuitBreaker::builder("test")
+            .failure_threshold(1)
+            .half_open_timeout_secs(0.0)
+            .success_threshold(1)
+            .build_async();
+        let _ = pollster::block_on(circuit.call(|| async { Err::<(), _>("error") }));
+
+        let mut probe =
+            Box::pin(circuit.call(std::future::pending::<Result<&'static str, &'static str>>));
+        assert!(poll_once(probe.as_mut()).is_pending());
+
+        let result = pollster::block_on(
+            circuit.call_with_options(
+                || async { Ok::<_, &str>("no probe slot") },
+                AsyncCallOptions::new()
+                    .with_fallback(|ctx| async move {
+                        assert_eq!(ctx.state, "HalfOpen");
+                        Ok("fallback")
+                    })
+                    .fallback_on(FallbackReason::HalfOpenLimitReached),
+            ),
+        );
+        assert_eq!(result.unwrap(), "fallback");
+    }
+
     #[test]
     fn async_call_with_fallback_future_is_send() {
         fn assert_send<T: Send>(_: T) {}
@@ -376,4 +1139,466 @@ mod tests {
 
         drop(fallback);
     }
+
+    #[test]
+    fn hedged_call_returns_first_success_and_releases_permits() {
+        let circuit = AsyncCircuitBreaker::builder("test")
+            .max_concurrency(2)
+            .build_async();
+
+        // The first attempt hangs; the hedge starts once the (instant) delay passes
+        let result = pollster::block_on(circuit.call_hedged(
+            HedgeOptions::new(|_| std::future::ready(())),
+            |attempt| async move {
+                if attempt == 0 {
+                    std::future::pending::<()>().await;
+                }
+                Ok::<_, &str>(attempt)
+            },
+        ));
+        assert_eq!(result.unwrap(), 1);
+
+        // Only the completed attempt is recorded; the dropped one freed its permit
+        let stats = circuit.lock_inner().stats();
+        assert_eq!((stats.success_count, stats.failure_count), (1, 0));
+        let mut first = Box::pin(circuit.call(std::future::pending::<Result<(), ()>>));
+        let mut second = Box::pin(circuit.call(std::future::pending::<Result<(), ()>>));
+        assert!(poll_once(first.as_mut()).is_pending());
+        assert!(poll_once(second.as_mut()).is_pending());
+    }
+
+    #[test]
+    fn hedged_call_accepts_an_infinite_delay() {
+        let circuit = AsyncCircuitBreaker::builder("test").build_async();
+
+        let result = pollster::block_on(
+            circuit.call_hedged(
+                HedgeOptions::new(|delay| {
+                    assert_eq!(delay, Duration::MAX);
+                    std::future::ready(())
+                })
+                .delay_secs(f64::INFINITY),
+                |attempt| async move {
+                    if attempt == 0 {
+                        std::future::pending::<()>().await;
+                    }
+                    Ok::<_, &str>(attempt)
+                },
+            ),
+        );
+        assert_eq!(result.unwrap(), 1);
+    }
+
+    #[test]
+    fn hedged_call_future_is_send() {
+        fn assert_send<T: Send>(_: T) {}
+
+        let circuit = AsyncCircuitBreaker::builder("test").build_async();
+        assert_send(circuit.call_hedged(
+            HedgeOptions::new(|_| std::future::ready(())),
+            |attempt| async move { Ok::<_, String>(attempt) },
+        ));
+    }
+
+    #[test]
+    fn hedged_call_starts_next_attempt_when_all_running_fail() {
+        let circuit = AsyncCircuitBreaker::builder("test")
+            .failure_threshold(10)
+            .build_async();
+        let launched = std::sync::atomic::AtomicUsize::new(0);
+
+        // The delay never passes, so hedges only start after failures
+        let result = pollster::block_on(circuit.call_hedged(
+            HedgeOptions::new(|_| std::future::pending::<()>()).max_requests(3),
+            |attempt| {
+                launched.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                async move { Err::<(), _>(attempt) }
+            },
+        ));
+        assert!(matches!(result, Err(CircuitError::Execution(0))));
+        assert_eq!(launched.load(std::sync::atomic::Ordering::SeqCst), 3);
+        assert_eq!(circuit.lock_inner().stats().failure_count, 3);
+    }
+
+    #[test]
+    fn hedged_call_stops_once_the_circuit_rejects() {
+        let circuit = AsyncCircuitBreaker::builder("test")
+            .failure_threshold(1)
+            .build_async();
+        let launched = std::sync::atomic::AtomicUsize::new(0);
+
+        let result = pollster::block_on(circuit.call_hedged(
+            HedgeOptions::new(|_| std::future::pending::<()>()).max_requests(5),
+            |_| {
+                launched.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                async { Err::<(), _>("down") }
+            },
+        ));
+        assert!(matches!(result, Err(CircuitError::Execution("down"))));
+        // The first failure trips the circuit, which rejects the second attempt
+        assert_eq!(launched.load(std::sync::atomic::Ordering::SeqCst), 2);
+        assert!(circuit.is_open());
+    }
+
+    #[test]
+    fn timed_out_call_records_a_failure_and_releases_its_permit() {
+        let circuit = AsyncCircuitBreaker::builder("test")
+            .failure_threshold(2)
+            .max_concurrency(1)
+            .call_timeout_secs(0.01)
+            .build_async();
+
+        let result = pollster::block_on(circuit.call(std::future::pending::<Result<(), ()>>));
+        assert!(matches!(
+            result,
+            Err(CircuitError::Timeout { ref circuit, timeout_secs }) if circuit == "test" && timeout_secs == 0.01
+        ));
+        assert_eq!(circuit.lock_inner().stats().failure_count, 1);
+
+        let result = pollster::block_on(circuit.call(|| async { Ok::<_, ()>("next") }));
+        assert_eq!(result.unwrap(), "next");
+    }
+
+    #[test]
+    fn timed_out_half_open_probe_reopens_the_circuit() {
+        let circuit = AsyncCircuitBreaker::builder("test")
+            .failure_threshold(1)
+            .half_open_timeout_secs(0.0)
+            .call_timeout_secs(0.01)
+            .build_async();
+
+        let _ = pollster::block_on(circuit.call(|| async { Err::<(), _>("error") }));
+        let result = pollster::block_on(circuit.call(std::future::pending::<Result<(), &str>>));
+        assert!(matches!(result, Err(CircuitError::Timeout { .. })));
+        assert!(circuit.is_open());
+
+        // The probe slot was released, so the next probe can close the circuit
+        let result = pollster::block_on(circuit.call(|| async { Ok::<_, &str>("probe") }));
+        assert_eq!(result.unwrap(), "probe");
+    }
+
+    #[test]
+    fn per_call_timeout_is_classified_and_falls_back() {
+        let circuit = AsyncCircuitBreaker::builder("test")
+            .failure_threshold(1)
+            .failure_classifier(Arc::new(crate::PredicateClassifier::new(|ctx| {
+                ctx.error.downcast_ref::<crate::CallTimeout>().is_none()
+            })))
+            .build_async();
+
+        let result = pollster::block_on(
+            circuit.call_with_options(
+                std::future::pending::<Result<&'static str, &'static str>>,
+                AsyncCallOptions::new()
+                    .with_timeout_secs(0.01)
+                    .with_fallback(|ctx| async move {
+                        assert_eq!(ctx.reason, FallbackReason::Timeout);
+                        Ok("cached")
+                    })
+                    .fallback_on(FallbackReason::Timeout),
+            ),
+        );
+        assert_eq!(result.unwrap(), "cached");
+        assert!(circuit.is_closed(), "the classifier ignores timeouts");
+    }
+
+    /// Async backend over `MemoryStorage` that can fail or suspend on demand
+    #[derive(Debug, Default)]
+    struct TestAsyncStorage {
+        inner: crate::MemoryStorage,
+        down: std::sync::atomic::AtomicBool,
+        suspend_records: std::sync::atomic::AtomicBool,
+    }
+
+    impl TestAsyncStorage {
+        fn check(&self) -> Result<(), StorageError> {
+            if self.down.load(std::sync::atomic::Ordering::SeqCst) {
+                Err(StorageError::Timeout)
+            } else {
+                Ok(())
+            }
+        }
+
+        async fn maybe_suspend(&self) {
+            if self
+                .suspend_records
+                .load(std::sync::atomic::Ordering::SeqCst)
+            {
+                std::future::pending::<()>().await;
+            }
+        }
+    }
+
+    impl AsyncStorageBackend for TestAsyncStorage {
+        fn record_success<'a>(
+            &'a self,
+            circuit_name: &'a str,
+            duration: f64,
+        ) -> crate::StorageFuture<'a, ()> {
+            Box::pin(async move {
+                self.maybe_suspend().await;
+                self.check()?;
+                self.inner.try_record_success(circuit_name, duration)
+            })
+        }
+
+        fn record_failure<'a>(
+            &'a self,
+            circuit_name: &'a str,
+            duration: f64,
+        ) -> crate::StorageFuture<'a, ()> {
+            Box::pin(async move {
+                self.maybe_suspend().await;
+                self.check()?;
+                self.inner.try_record_failure(circuit_name, duration)
+            })
+        }
+
+        fn success_count<'a>(
+            &'a self,
+            circuit_name: &'a str,
+            window_seconds: f64,
+        ) -> crate::StorageFuture<'a, usize> {
+            Box::pin(async move {
+                self.check()?;
+                self.inner.try_success_count(circuit_name, window_seconds)
+            })
+        }
+
+        fn failure_count<'a>(
+            &'a self,
+            circuit_name: &'a str,
+            window_seconds: f64,
+        ) -> crate::StorageFuture<'a, usize> {
+            Box::pin(async move {
+                self.check()?;
+                self.inner.try_failure_count(circuit_name, window_seconds)
+            })
+        }
+
+        fn record_slow_call<'a>(
+            &'a self,
+            circuit_name: &'a str,
+            duration: f64,
+        ) -> crate::StorageFuture<'a, ()> {
+            Box::pin(async move {
+                self.check()?;
+                self.inner.try_record_slow_call(circuit_name, duration)
+            })
+        }
+
+        fn slow_call_count<'a>(
+            &'a self,
+            circuit_name: &'a str,
+            window_seconds: f64,
+        ) -> crate::StorageFuture<'a, usize> {
+            Box::pin(async move {
+                self.check()?;
+                self.inner.try_slow_call_count(circuit_name, window_seconds)
+            })
+        }
+
+        fn recent_calls<'a>(
+            &'a self,
+            circuit_name: &'a str,
+            calls: usize,
+            slow_call_secs: Option<f64>,
+        ) -> crate::StorageFuture<'a, CallCounts> {
+            Box::pin(async move {
+                self.check()?;
+                self.inner
+                    .try_recent_calls(circuit_name, calls, slow_call_secs)
+            })
+        }
+
+        fn clear<'a>(&'a self, circuit_name: &'a str) -> crate::StorageFuture<'a, ()> {
+            Box::pin(async move { self.inner.try_clear(circuit_name) })
+        }
+
+        fn monotonic_time(&self) -> f64 {
+            self.inner.monotonic_time()
+        }
+
+        fn get_status<'a>(
+            &'a self,
+            circuit_name: &'a str,
+        ) -> crate::StorageFuture<'a, Option<CircuitStatus>> {
+            Box::pin(async move {
+                self.check()?;
+                self.inner.try_get_status(circuit_name)
+            })
+        }
+
+        fn set_status<'a>(
+            &'a self,
+            circuit_name: &'a str,
+            status: CircuitStatus,
+        ) -> crate::StorageFuture<'a, ()> {
+            Box::pin(async move {
+                self.check()?;
+                self.inner.try_set_status(circuit_name, status)
+            })
+        }
+    }
+
+    #[test]
+    fn async_storage_records_trips_and_persists() {
+        let storage = Arc::new(TestAsyncStorage::default());
+        let circuit = AsyncCircuitBreaker::builder("test")
+            .async_storage(storage.clone())
+            .failure_threshold(2)
+            .build_async();
+
+        pollster::block_on(async {
+            let _ = circuit.call(|| async { Ok::<_, &str>("ok") }).await;
+            let _ = circuit.call(|| async { Err::<(), _>("error 1") }).await;
+            assert!(circuit.is_closed());
+            let _ = circuit.call(|| async { Err::<(), _>("error 2") }).await;
+        });
+
+        assert!(circuit.is_open());
+        assert_eq!(storage.inner.success_count("test", 60.0), 1);
+        assert_eq!(storage.inner.failure_count("test", 60.0), 2);
+        assert_eq!(
+            storage.inner.get_status("test").map(|s| s.state),
+            Some(crate::CircuitState::Open)
+        );
+
+        pollster::block_on(circuit.reset_async());
+        assert_eq!(storage.inner.failure_count("test", 60.0), 0);
+        assert_eq!(
+            storage.inner.get_status("test").map(|s| s.state),
+            Some(crate::CircuitState::Closed)
+        );
+    }
+
+    #[test]
+    fn async_storage_slow_successes_trip() {
+        let storage = Arc::new(TestAsyncStorage::default());
+        let circuit = AsyncCircuitBreaker::builder("test")
+            .async_storage(storage.clone())
+            .disable_failure_threshold()
+            .slow_call_duration_secs(0.0)
+            .slow_call_rate(1.0)
+            .minimum_calls(2)
+            .build_async();
+
+        pollster::block_on(async {
+            let _ = circuit.call(|| async { Ok::<_, &str>("slow 1") }).await;
+            assert!(circuit.is_closed());
+            let _ = circuit.call(|| async { Ok::<_, &str>("slow 2") }).await;
+        });
+
+        assert!(circuit.is_open());
+        assert_eq!(storage.inner.slow_call_count("test", 60.0), 2);
+    }
+
+    #[test]
+    fn async_storage_count_window_trips() {
+        let storage = Arc::new(TestAsyncStorage::default());
+        let circuit = AsyncCircuitBreaker::builder("test")
+            .async_storage(storage)
+            .failure_threshold(2)
+            .count_window(3)
+            .build_async();
+
+        pollster::block_on(async {
+            let _ = circuit.call(|| async { Err::<(), _>("error 1") }).await;
+            let _ = circuit.call(|| async { Ok::<_, &str>("ok") }).await;
+            assert!(circuit.is_closed());
+            let _ = circuit.call(|| async { Err::<(), _>("error 2") }).await;
+        });
+
+        assert!(circuit.is_open());
+    }
+
+    #[test]
+    fn async_storage_overrides_pin_the_state() {
+        let storage = Arc::new(TestAsyncStorage::default());
+        let circuit = AsyncCircuitBreaker::builder("test")
+            .async_storage(storage.clone())
+            .failure_threshold(1)
+            .build_async();
+
+        pollster::block_on(async {
+            circuit.force_open();
+            let result = circuit.call(|| async { Ok::<_, &str>("blocked") }).await;
+            assert!(matches!(result, Err(CircuitError::Open { .. })));
+            assert_eq!(circuit.state_name(), "ForcedOpen");
+
+            circuit.disable();
+            let result = circuit.call(|| async { Err::<(), _>("ignored") }).await;
+            assert!(matches!(result, Err(CircuitError::Execution("ignored"))));
+        });
+
+        assert_eq!(circuit.override_mode(), Some(CircuitOverride::Disabled));
+        assert!(circuit.is_closed());
+        assert_eq!(storage.inner.failure_count("test", 60.0), 0);
+    }
+
+    #[test]
+    fn async_storage_is_not_awaited_under_the_lock() {
+        let storage = Arc::new(TestAsyncStorage::default());
+        let circuit = AsyncCircuitBreaker::builder("test")
+            .async_storage(storage.clone())
+            .build_async();
+
+        storage
+            .suspend_records
+            .store(true, std::sync::atomic::Ordering::SeqCst);
+        let mut call = Box::pin(circuit.call(|| async { Err::<(), _>("error") }));
+        assert!(matches!(poll_once(call.as_mut()), std::task::Poll::Pending));
+
+        assert!(circuit.inner.try_lock().is_ok());
+        drop(call);
+    }
+
+    #[test]
+    fn async_storage_restores_persisted_open_state() {
+        let storage = Arc::new(TestAsyncStorage::default());
+        storage
+            .inner
+            .set_status("test", CircuitStatus::open(storage.inner.monotonic_time()));
+
+        let circuit = AsyncCircuitBreaker::builder("test")
+            .async_storage(storage)
+            .half_open_timeout_secs(60.0)
+            .build_async();
+
+        let result = pollster::block_on(circuit.call(|| async { Ok::<_, &str>("blocked") }));
+        assert!(matches!(result, Err(CircuitError::Open { .. })));
+    }
+
+    #[test]
+    fn async_storage_errors_follow_failure_policy() {
+        let storage = Arc::new(TestAsyncStorage::default());
+        let circuit = AsyncCircuitBreaker::builder("test")
+            .async_storage(storage.clone())
+            .failure_threshold(1)
+            .storage_failure_policy(crate::StorageFailurePolicy::FailClosed)
+            .build_async();
+
+        storage
+            .down
+            .store(true, std::sync::atomic::Ordering::SeqCst);
+        pollster::block_on(async {
+            let _ = circuit.call(|| async { Err::<(), _>("uncounted") }).await;
+            assert!(circuit.is_closed());
+
+            let result = circuit.call(|| async { Ok::<_, &str>("rejected") }).await;
+            assert!(matches!(
+                result,
+                Err(CircuitError::Storage {
+                    error: StorageError::Timeout,
+                    ..
+                })
+            ));
+
+            storage
+                .down
+                .store(false, std::sync::atomic::Ordering::SeqCst);
+            let result = circuit.call(|| async { Ok::<_, &str>("recovered") }).await;
+            assert_eq!(result.unwrap(), "recovered");
+        });
+    }
 }
@@ -1,14 +1,17 @@
 //! Builder API for ergonomic circuit breaker configuration
 
 use crate::{
-    MemoryStorage, StorageBackend,
+    FallbackChainStorage, MemoryStorage, StorageBackend,
     bulkhead::BulkheadSemaphore,
     callbacks::Callbacks,
-    circuit::{CircuitBreaker, CircuitContext, Config},
+    cascade::CascadeTarget,
+    circuit::{CircuitBreaker, CircuitContext, Config, SlidingWindow, StorageFailurePolicy},
     classifier::FailureClassifier,
+    recovery::RecoveryGuard,
 };
 use alloc::string::String;
 use alloc::sync::Arc;
+use alloc::vec::Vec;
 
 /// Builder for creating circuit breakers with fluent API
 pub struct CircuitBuilder {
@@ -17,7 +20,11 @@ pub struct CircuitBuilder {
     storage: Option<Arc<dyn StorageBackend>>,
     failure_classifier: Option<Arc<dyn FailureClassifier>>,
     bulkhead: Option<Arc<BulkheadSemaphore>>,
+    recovery_guard: Option<Arc<dyn RecoveryGuard>>,
     callbacks: Callbacks,
+    cascades_to: Vec<CascadeTarget>,
+    #[cfg(feature = "async")]
+    async_storage: Option<Arc<dyn crate::AsyncStorageBackend>>,
 }
 
 impl CircuitBuilder {
@@ -29,7 +36,11 @@ impl CircuitBuilder {
             storage: None,
             failure_classifier: None,
             bulkhead: None,
+            recovery_guard: None,
             callbacks: Callbacks::new(),
+            cascades_to: Vec::new(),
+            #[cfg(feature = "async")]
+            async_storage: None,
         }
     }
 
@@ -58,18 +69,48 @@ impl CircuitBuilder {
         self
     }
 
+    /// Set the duration in seconds at or above which a call counts as slow
+    pub fn slow_call_duration_secs(mut self, seconds: f64) -> Self {
+        self.config.slow_call_duration_secs = Some(seconds);
+        self
+    }
+
+    /// Set the slow-call rate threshold (0.0-1.0)
+    /// Circuit opens when (slow_calls / total_calls) >= this value
+    pub fn slow_call_rate(mut self, rate: f64) -> Self {
+        self.config.slow_call_rate_threshold = Some(rate.clamp(0.0, 1.0));
+        self
+    }
+
     /// Set the failure window in seconds
     pub fn failure_window_secs(mut self, seconds: f64) -> Self {
         self.config.failure_window_secs = seconds;
         self
     }
 
+    /// Evaluate thresholds over the last `calls` recorded calls instead of
+    /// `failure_window_secs`
+    pub fn count_window(mut self, calls: usize) -> Self {
+        self.config.sliding_window = SlidingWindow::Count(calls);
+        self
+    }
+
     /// Set the half-open timeout in seconds
     pub fn half_open_timeout_secs(mut self, seconds: f64) -> Self {
         self.config.half_open_timeout_secs = seconds;
         self
     }
 
+    /// Grow the half-open timeout by `multiplier` after each failed probe,
+    /// up to `max_timeout_secs`
+    ///
+    /// The timeout returns to `half_open_timeout_secs` once the circuit closes.
+    pub fn half_open_backoff(mut self, multiplier: f64, max_timeout_secs: f64) -> Self {
+        self.config.half_open_backoff_multiplier = multiplier.max(1.0);
+        self.config.max_half_open_timeout_secs = Some(max_timeout_secs);
+        self
+    }
+
     /// Set the success threshold (successes needed to close from half-open)
     pub fn success_threshold(mut self, threshold: usize) -> Self {
         self.config.success_threshold = threshold;
@@ -83,12 +124,59 @@ impl CircuitBuilder {
         self
     }
 
+    /// Set the seconds a call may run before it times out
+    ///
+    /// Applies to async calls and to
+    /// [`CircuitBreaker::call_with_timeout`](crate::CircuitBreaker::call_with_timeout).
+    pub fn call_timeout_secs(mut self, seconds: f64) -> Self {
+        self.config.call_timeout_secs = Some(seconds);
+        self
+    }
+
+    /// Set how many timed-out sync calls may still be running on their
+    /// worker threads before new timed calls are refused
+    ///
+    /// See [`CircuitBreaker::call_with_timeout`](crate::CircuitBreaker::call_with_timeout).
+    pub fn max_abandoned_calls(mut self, limit: usize) -> Self {
+        self.config.max_abandoned_calls = limit;
+        self
+    }
+
     /// Set custom storage backend
     pub fn storage(mut self, storage: Arc<dyn StorageBackend>) -> Self {
         self.storage = Some(storage);
         self
     }
 
+    /// Set an async storage backend, used by [`build_async`](Self::build_async)
+    ///
+    /// The async circuit awaits the backend outside its internal lock, so I/O
+    /// never blocks the executor. Replaces any [`storage`](Self::storage);
+    /// [`build`](Self::build) ignores it.
+    #[cfg(feature = "async")]
+    pub fn async_storage(mut self, storage: Arc<dyn crate::AsyncStorageBackend>) -> Self {
+        self.async_storage = Some(storage);
+        self
+    }
+
+    /// Set what the circuit does when the storage backend reports errors
+    ///
+    /// Defaults to [`StorageFailurePolicy::FailOpen`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use breaker_machines::{CircuitBreaker, StorageFailurePolicy};
+    ///
+    /// let circuit = CircuitBreaker::builder("payments")
+    ///     .storage_failure_policy(StorageFailurePolicy::FailClosed)
+    ///     .build();
+    /// ```
+    pub fn storage_failure_policy(mut self, policy: StorageFailurePolicy) -> Self {
+        self.config.storage_failure_policy = policy;
+        self
+    }
+
     /// Set a failure classifier to filter which errors should trip the circuit
     ///
     /// The classifier determines whether a given error should count toward
@@ -113,6 +201,33 @@ impl CircuitBuilder {
         self
     }
 
+    /// Set a recovery guard to coordinate recovery with external health signals
+    ///
+    /// The guard is consulted before an Open circuit whose timeout has elapsed
+    /// moves to HalfOpen, and before a HalfOpen circuit closes. While it
+    /// refuses, the circuit stays in its current state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use breaker_machines::{CircuitBreaker, PredicateRecoveryGuard};
+    /// use std::sync::Arc;
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    ///
+    /// let database_up = Arc::new(AtomicBool::new(true));
+    /// let signal = database_up.clone();
+    ///
+    /// let circuit = CircuitBreaker::builder("api")
+    ///     .recovery_guard(Arc::new(PredicateRecoveryGuard::new(move |_| {
+    ///         signal.load(Ordering::Acquire)
+    ///     })))
+    ///     .build();
+    /// ```
+    pub fn recovery_guard(mut self, guard: Arc<dyn RecoveryGuard>) -> Self {
+        self.recovery_guard = Some(guard);
+        self
+    }
+
     /// Set maximum concurrency limit (bulkheading)
     ///
     /// When set, the circuit breaker will reject calls with `BulkheadFull` error
@@ -167,11 +282,55 @@ impl CircuitBuilder {
         self
     }
 
+    /// Set callback for when a parent's cascade forces this circuit open
+    ///
+    /// Receives this circuit's name and the name of the parent that opened.
+    pub fn on_cascade<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&str, &str) + Send + Sync + 'static,
+    {
+        self.callbacks.on_cascade = Some(Arc::new(f));
+        self
+    }
+
+    /// Force a dependent circuit open whenever this circuit opens
+    ///
+    /// The dependent rejects calls until this circuit has closed again. May be
+    /// called once per dependent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use breaker_machines::CircuitBreaker;
+    ///
+    /// let mut reports = CircuitBreaker::builder("reports").build();
+    /// let mut database = CircuitBreaker::builder("database")
+    ///     .cascades_to(reports.cascade_target())
+    ///     .build();
+    ///
+    /// database.force_open();
+    /// assert!(reports.call(|| Ok::<_, String>("report")).is_err());
+    /// assert_eq!(reports.cascade_source().as_deref(), Some("database"));
+    /// ```
+    pub fn cascades_to(mut self, target: CascadeTarget) -> Self {
+        self.cascades_to.push(target);
+        self
+    }
+
     /// Build the circuit breaker
     pub fn build(self) -> CircuitBreaker {
         let storage = self
             .storage
             .unwrap_or_else(|| Arc::new(MemoryStorage::new()));
+        let storage: Arc<dyn StorageBackend> =
+            if self.config.storage_failure_policy == StorageFailurePolicy::FallbackToMemory {
+                Arc::new(FallbackChainStorage::new(alloc::vec![
+                    storage,
+                    Arc::new(MemoryStorage::new()),
+                ]))
+            } else {
+                storage
+            };
 
         let context = CircuitContext {
             name: self.name,
@@ -179,15 +338,30 @@ impl CircuitBuilder {
             storage,
             failure_classifier: self.failure_classifier,
             bulkhead: self.bulkhead,
+            recovery_guard: self.recovery_guard,
         };
 
-        CircuitBreaker::with_context_and_callbacks(context, self.callbacks)
+        CircuitBreaker::with_context_and_callbacks(context, self.callbacks, self.cascades_to)
+    }
+
+    /// Build a cloneable circuit breaker handle that can be shared across threads
+    #[cfg(feature = "std")]
+    pub fn build_shared(self) -> crate::SharedCircuitBreaker {
+        crate::SharedCircuitBreaker::from_circuit(self.build())
     }
 
     /// Build an async-friendly circuit breaker wrapper.
     #[cfg(feature = "async")]
-    pub fn build_async(self) -> crate::async_circuit::AsyncCircuitBreaker {
-        crate::async_circuit::AsyncCircuitBreaker::from_circuit(self.build())
+    pub fn build_async(mut self) -> crate::async_circuit::AsyncCircuitBreaker {
+        use crate::async_circuit::{AsyncCircuitBreaker, StorageMirror};
+
+        let Some(backend) = self.async_storage.take() else {
+            return AsyncCircuitBreaker::from_circuit(self.build());
+        };
+
+        let mirror = Arc::new(StorageMirror::new(backend, self.name.clone()));
+        self.storage = Some(Arc::clone(&mirror) as Arc<dyn StorageBackend>);
+        AsyncCircuitBreaker::with_storage_mirror(self.build(), mirror)
     }
 }
 
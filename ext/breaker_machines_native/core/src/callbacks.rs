@@ -5,12 +5,17 @@ use alloc::sync::Arc;
 /// Type alias for circuit breaker callback functions
 pub type CallbackFn = Arc<dyn Fn(&str) + Send + Sync>;
 
+/// Callback invoked with the circuit name and the name of the circuit whose
+/// trip cascaded to it
+pub type CascadeCallbackFn = Arc<dyn Fn(&str, &str) + Send + Sync>;
+
 /// Callbacks for circuit breaker events
 #[derive(Clone)]
 pub struct Callbacks {
     pub on_open: Option<CallbackFn>,
     pub on_close: Option<CallbackFn>,
     pub on_half_open: Option<CallbackFn>,
+    pub on_cascade: Option<CascadeCallbackFn>,
 }
 
 impl Callbacks {
@@ -19,6 +24,7 @@ impl Callbacks {
             on_open: None,
             on_close: None,
             on_half_open: None,
+            on_cascade: None,
         }
     }
 
@@ -50,6 +56,19 @@ impl Callbacks {
     pub fn trigger_half_open(&self, circuit: &str) {
         Self::trigger(&self.on_half_open, circuit);
     }
+
+    /// Trigger the on_cascade callback safely.
+    pub fn trigger_cascade(&self, circuit: &str, source: &str) {
+        if let Some(callback) = &self.on_cascade {
+            #[cfg(feature = "std")]
+            {
+                let cb = std::panic::AssertUnwindSafe(callback);
+                let _ = std::panic::catch_unwind(|| cb(circuit, source));
+            }
+            #[cfg(not(feature = "std"))]
+            callback(circuit, source);
+        }
+    }
 }
 
 impl Default for Callbacks {
@@ -64,6 +83,7 @@ impl core::fmt::Debug for Callbacks {
             .field("on_open", &self.on_open.is_some())
             .field("on_close", &self.on_close.is_some())
             .field("on_half_open", &self.on_half_open.is_some())
+            .field("on_cascade", &self.on_cascade.is_some())
             .finish()
     }
 }
@@ -80,12 +100,14 @@ mod tests {
             on_open: Some(Arc::new(|_| panic!("intentional panic in on_open"))),
             on_close: Some(Arc::new(|_| panic!("intentional panic in on_close"))),
             on_half_open: Some(Arc::new(|_| panic!("intentional panic in on_half_open"))),
+            on_cascade: Some(Arc::new(|_, _| panic!("intentional panic in on_cascade"))),
         };
 
         // These should not panic - the panics are caught internally
         callbacks.trigger_open("test");
         callbacks.trigger_close("test");
         callbacks.trigger_half_open("test");
+        callbacks.trigger_cascade("test", "parent");
     }
 
     #[test]
@@ -108,6 +130,7 @@ mod tests {
             on_half_open: Some(Arc::new(move |_| {
                 half_open_clone.store(true, Ordering::SeqCst);
             })),
+            on_cascade: None,
         };
 
         callbacks.trigger_open("test");
@@ -139,6 +162,7 @@ mod tests {
             })),
             on_close: None,
             on_half_open: None,
+            on_cascade: None,
         };
 
         callbacks.trigger_open("my_circuit");
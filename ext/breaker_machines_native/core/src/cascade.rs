@@ -0,0 +1,114 @@
+//! Cascading trips between circuits
+//!
+//! A circuit built with [`CircuitBuilder::cascades_to`](crate::CircuitBuilder::cascades_to)
+//! forces its dependents open whenever it opens, and releases them once it
+//! closes again, is forced closed or disabled, or is dropped. This mirrors the
+//! Ruby `CascadingCircuit`'s `cascades_to`. A dependent can also drop every
+//! latch itself with
+//! [`CircuitBreaker::clear_cascade`](crate::CircuitBreaker::clear_cascade).
+//!
+//! The parent never locks a dependent: it only flips the dependent's
+//! [`CascadeTarget`], which the dependent consults before each call. Circuits
+//! may therefore cascade to each other, or to circuits behind any handle,
+//! without lock-ordering concerns.
+
+use crate::callbacks::Callbacks;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicBool, Ordering};
+use spin::RwLock;
+
+#[derive(Debug)]
+struct Latch {
+    circuit: String,
+    /// Open parents whose trip cascaded here, in the order they tripped
+    sources: RwLock<Vec<String>>,
+    tripped: AtomicBool,
+    callbacks: Callbacks,
+}
+
+/// Handle through which parent circuits force a dependent open
+///
+/// Obtained from the dependent with
+/// [`CircuitBreaker::cascade_target`](crate::CircuitBreaker::cascade_target)
+/// and passed to the parent's builder. The dependent rejects calls while any
+/// parent is open.
+#[derive(Debug, Clone)]
+pub struct CascadeTarget {
+    latch: Arc<Latch>,
+}
+
+impl CascadeTarget {
+    pub(crate) fn new(circuit: String, callbacks: Callbacks) -> Self {
+        Self {
+            latch: Arc::new(Latch {
+                circuit,
+                sources: RwLock::new(Vec::new()),
+                tripped: AtomicBool::new(false),
+                callbacks,
+            }),
+        }
+    }
+
+    /// Name of the dependent circuit
+    pub fn circuit_name(&self) -> &str {
+        &self.latch.circuit
+    }
+
+    /// Force the dependent open on behalf of `source`
+    ///
+    /// The first source to latch fires the dependent's `on_open`; every
+    /// source fires its `on_cascade` with the source's name.
+    pub(crate) fn trip(&self, source: &str) {
+        let newly_open = {
+            let mut sources = self.latch.sources.write();
+            if sources.iter().any(|s| s == source) {
+                return;
+            }
+            sources.push(String::from(source));
+            !self.latch.tripped.swap(true, Ordering::AcqRel)
+        };
+        let callbacks = &self.latch.callbacks;
+        if newly_open {
+            callbacks.trigger_open(&self.latch.circuit);
+        }
+        callbacks.trigger_cascade(&self.latch.circuit, source);
+    }
+
+    /// Withdraw `source`'s trip; the dependent recovers once no source is left
+    ///
+    /// Removing the last source fires the dependent's `on_close`.
+    pub(crate) fn release(&self, source: &str) {
+        let recovered = {
+            let mut sources = self.latch.sources.write();
+            sources.retain(|s| s != source);
+            sources.is_empty() && self.latch.tripped.swap(false, Ordering::AcqRel)
+        };
+        if recovered {
+            self.latch.callbacks.trigger_close(&self.latch.circuit);
+        }
+    }
+
+    /// Withdraw every source's trip at once, firing `on_close` if any was set
+    pub(crate) fn release_all(&self) {
+        let recovered = {
+            let mut sources = self.latch.sources.write();
+            sources.clear();
+            self.latch.tripped.swap(false, Ordering::AcqRel)
+        };
+        if recovered {
+            self.latch.callbacks.trigger_close(&self.latch.circuit);
+        }
+    }
+
+    /// Whether any parent currently holds the dependent open
+    pub(crate) fn is_tripped(&self) -> bool {
+        self.latch.tripped.load(Ordering::Acquire)
+    }
+
+    /// The first parent still holding the dependent open
+    pub(crate) fn source(&self) -> Option<String> {
+        self.latch.sources.read().first().cloned()
+    }
+}
@@ -3,12 +3,20 @@
 //! This module provides a complete circuit breaker with state management.
 
 use crate::{
-    StorageBackend, bulkhead::BulkheadSemaphore, callbacks::Callbacks,
-    classifier::FailureClassifier, errors::CircuitError,
+    CallCounts, CircuitStatus, StorageBackend,
+    bulkhead::BulkheadSemaphore,
+    callbacks::Callbacks,
+    cascade::CascadeTarget,
+    classifier::FailureClassifier,
+    errors::{CircuitError, StorageError},
+    recovery::{RecoveryContext, RecoveryGuard},
 };
 use alloc::boxed::Box;
 use alloc::string::String;
 use alloc::sync::Arc;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::sync::atomic::{AtomicBool, Ordering};
 use state_machines::state_machine;
 
 /// Circuit breaker configuration
@@ -25,18 +33,108 @@ pub struct Config {
     /// Minimum number of calls before rate-based threshold is evaluated
     pub minimum_calls: usize,
 
-    /// Time window in seconds for counting failures
+    /// Time window in seconds for counting failures and slow calls
     pub failure_window_secs: f64,
 
+    /// Whether thresholds are evaluated over a time window or the last N calls
+    pub sliding_window: SlidingWindow,
+
+    /// Calls taking at least this many seconds count as slow, whether they
+    /// succeed or fail. If None, call latency is not tracked
+    pub slow_call_duration_secs: Option<f64>,
+
+    /// Slow-call rate threshold (0.0-1.0) - percentage of slow calls to open circuit
+    /// Evaluated after `minimum_calls`, like the failure rate
+    pub slow_call_rate_threshold: Option<f64>,
+
     /// Timeout in seconds before transitioning from Open to HalfOpen
     pub half_open_timeout_secs: f64,
 
+    /// Growth of the Open timeout for each consecutive HalfOpen -> Open trip
+    /// (1.0 = constant). Resets once the circuit closes
+    pub half_open_backoff_multiplier: f64,
+
+    /// Upper bound in seconds for the backed-off Open timeout
+    /// If None, the timeout grows without bound
+    pub max_half_open_timeout_secs: Option<f64>,
+
     /// Number of successes required in HalfOpen to close the circuit
     pub success_threshold: usize,
 
     /// Jitter factor for half_open_timeout (0.0 = no jitter, 1.0 = full jitter)
     /// Uses chrono-machines formula: timeout * (1 - jitter + rand * jitter)
     pub jitter_factor: f64,
+
+    /// What the circuit does when its storage backend reports an error
+    pub storage_failure_policy: StorageFailurePolicy,
+
+    /// Seconds an async call, or a sync call made with
+    /// [`CircuitBreaker::call_with_timeout`], may run before it is abandoned
+    /// and recorded as a failure. If None, calls run to completion
+    pub call_timeout_secs: Option<f64>,
+
+    /// Timed-out sync calls whose worker threads may still be running before
+    /// [`CircuitBreaker::call_with_timeout`] refuses new calls with
+    /// [`CircuitError::TimeoutWorkersExhausted`]
+    pub max_abandoned_calls: usize,
+}
+
+/// Which calls failure and slow-call thresholds are evaluated over
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SlidingWindow {
+    /// Calls within the last `failure_window_secs`
+    #[default]
+    Time,
+    /// The last N recorded calls, regardless of age
+    ///
+    /// Suits low-traffic circuits, where a time window may hold too few calls
+    /// to ever reach `minimum_calls`.
+    Count(usize),
+}
+
+/// How a circuit behaves while its storage backend is failing
+///
+/// Only backends that report errors through the `try_*` methods of
+/// [`StorageBackend`] (such as `RespStorage`) can trigger a policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StorageFailurePolicy {
+    /// Let calls through; failures that can't be counted never trip the circuit
+    #[default]
+    FailOpen,
+    /// Reject calls with [`CircuitError::Storage`] until the backend answers again
+    FailClosed,
+    /// Count in a process-local `MemoryStorage` while the backend is failing
+    ///
+    /// Applied by [`CircuitBuilder::build`](crate::CircuitBuilder::build), which
+    /// chains the configured storage with a memory backend.
+    FallbackToMemory,
+}
+
+/// Operator override that takes precedence over the configured thresholds
+///
+/// Set with [`CircuitBreaker::force_open`], [`CircuitBreaker::force_close`] or
+/// [`CircuitBreaker::disable`], and lifted with
+/// [`CircuitBreaker::release_override`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitOverride {
+    /// Reject every call; no half-open probe is ever let through
+    ForcedOpen,
+    /// Run every call and record its outcome, but never trip
+    ForcedClosed,
+    /// Pass calls straight through, without recording them or enforcing the
+    /// bulkhead
+    Disabled,
+}
+
+impl CircuitOverride {
+    /// Name reported by [`CircuitBreaker::state_name`] while the override is set
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::ForcedOpen => "ForcedOpen",
+            Self::ForcedClosed => "ForcedClosed",
+            Self::Disabled => "Disabled",
+        }
+    }
 }
 
 impl Default for Config {
@@ -46,36 +144,181 @@ impl Default for Config {
             failure_rate_threshold: None,
             minimum_calls: 20,
             failure_window_secs: 60.0,
+            sliding_window: SlidingWindow::Time,
+            slow_call_duration_secs: None,
+            slow_call_rate_threshold: None,
             half_open_timeout_secs: 30.0,
+            half_open_backoff_multiplier: 1.0,
+            max_half_open_timeout_secs: None,
             success_threshold: 2,
             jitter_factor: 0.0,
+            storage_failure_policy: StorageFailurePolicy::FailOpen,
+            call_timeout_secs: None,
+            max_abandoned_calls: 16,
+        }
+    }
+}
+
+/// Point-in-time view of a circuit, returned by [`CircuitBreaker::stats`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CircuitStats {
+    /// Current state
+    pub state: CircuitState,
+    /// Failures within the sliding window
+    pub failure_count: usize,
+    /// Successes within the sliding window
+    pub success_count: usize,
+    /// Calls within the sliding window that took `slow_call_duration_secs` or longer
+    pub slow_call_count: usize,
+    /// When the circuit opened, if it is Open
+    pub opened_at: Option<f64>,
+}
+
+/// Why a call was handed to its fallbacks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FallbackReason {
+    /// The circuit is open
+    Open,
+    /// The operation returned an error
+    Execution,
+    /// The bulkhead had no permit left
+    BulkheadFull,
+    /// The HalfOpen circuit had no probe slot left
+    HalfOpenLimitReached,
+    /// The operation ran past its call timeout
+    Timeout,
+    /// Too many timed-out sync calls were still running to start another
+    TimeoutWorkersExhausted,
+}
+
+impl FallbackReason {
+    fn of<E>(error: &CircuitError<E>) -> Option<Self> {
+        match error {
+            CircuitError::Open { .. } => Some(Self::Open),
+            CircuitError::Execution(_) => Some(Self::Execution),
+            CircuitError::BulkheadFull { .. } => Some(Self::BulkheadFull),
+            CircuitError::HalfOpenLimitReached { .. } => Some(Self::HalfOpenLimitReached),
+            CircuitError::Timeout { .. } => Some(Self::Timeout),
+            CircuitError::TimeoutWorkersExhausted { .. } => Some(Self::TimeoutWorkersExhausted),
+            _ => None,
+        }
+    }
+}
+
+/// Reasons for which fallbacks run; always includes [`FallbackReason::Open`]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct FallbackTriggers(u8);
+
+impl Default for FallbackTriggers {
+    fn default() -> Self {
+        Self(1 << FallbackReason::Open as u8)
+    }
+}
+
+impl FallbackTriggers {
+    pub(crate) fn insert(&mut self, reason: FallbackReason) {
+        self.0 |= 1 << reason as u8;
+    }
+
+    /// Reason to hand `error` to the fallbacks, if it is one they handle
+    fn reason_for<E>(self, error: &CircuitError<E>) -> Option<FallbackReason> {
+        FallbackReason::of(error).filter(|reason| self.0 & (1 << *reason as u8) != 0)
+    }
+
+    /// Decide whether a call's result goes to its fallbacks
+    ///
+    /// `context` is the one an Open rejection hands back; for any other error
+    /// `fallback_context` builds one for the reason.
+    pub(crate) fn dispatch<T, E>(
+        self,
+        has_fallbacks: bool,
+        result: Result<T, CircuitError<E>>,
+        context: Option<FallbackContext>,
+        fallback_context: impl FnOnce(FallbackReason) -> FallbackContext,
+    ) -> FallbackDispatch<T, E> {
+        let error = match result {
+            Err(error) if has_fallbacks => error,
+            result => return FallbackDispatch::Return(result),
+        };
+        match self.reason_for(&error) {
+            Some(reason) => FallbackDispatch::Fallback {
+                context: context.unwrap_or_else(|| fallback_context(reason)),
+                error,
+            },
+            None => FallbackDispatch::Return(Err(error)),
         }
     }
 }
 
-/// Context provided to fallback closures when circuit is open
+/// What to do with a call's result, see [`FallbackTriggers::dispatch`]
+pub(crate) enum FallbackDispatch<T, E> {
+    /// Hand the result back as it is
+    Return(Result<T, CircuitError<E>>),
+    /// Run the fallbacks in place of `error`
+    Fallback {
+        context: FallbackContext,
+        error: CircuitError<E>,
+    },
+}
+
+/// Context provided to fallback closures
+///
+/// Only built by the circuit; fields may be added in minor releases.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct FallbackContext {
     /// Circuit name
     pub circuit_name: String,
     /// Timestamp when circuit opened
     pub opened_at: f64,
+    /// Consecutive Open periods without a successful close, starting at 1
+    pub attempt: u32,
+    /// Seconds until the circuit lets a half-open probe through
+    ///
+    /// Infinite while the circuit is forced open.
+    pub retry_after_secs: f64,
+    /// Open timeout of this period (configured timeout after backoff and jitter)
+    pub timeout_secs: f64,
     /// Current circuit state
     pub state: &'static str,
+    /// Why the fallbacks run
+    pub reason: FallbackReason,
+}
+
+impl FallbackContext {
+    /// The error returned when no fallback handles the rejected call
+    pub(crate) fn into_open_error<E>(self) -> CircuitError<E> {
+        CircuitError::Open {
+            circuit: self.circuit_name,
+            opened_at: self.opened_at,
+            retry_after_secs: self.retry_after_secs,
+            timeout_secs: self.timeout_secs,
+        }
+    }
 }
 
 /// Type alias for fallback function
 pub type FallbackFn<T, E> = Box<dyn FnOnce(&FallbackContext) -> Result<T, E> + Send>;
 
 /// Options for circuit breaker calls
+///
+/// Build with [`CallOptions::new`] and [`with_fallback`](Self::with_fallback),
+/// which takes the place of the former `fallback` field; calling it once
+/// still sets a single fallback. Fields may be added in minor releases.
+#[non_exhaustive]
 pub struct CallOptions<T, E> {
-    /// Optional fallback function called when circuit is open
-    pub fallback: Option<FallbackFn<T, E>>,
+    /// Fallbacks tried in order until one succeeds
+    pub fallbacks: Vec<FallbackFn<T, E>>,
+    fallback_on: FallbackTriggers,
 }
 
 impl<T, E> Default for CallOptions<T, E> {
     fn default() -> Self {
-        Self { fallback: None }
+        Self {
+            fallbacks: Vec::new(),
+            fallback_on: FallbackTriggers::default(),
+        }
     }
 }
 
@@ -85,14 +328,58 @@ impl<T, E> CallOptions<T, E> {
         Self::default()
     }
 
-    /// Set a fallback function
+    /// Add a fallback function
+    ///
+    /// Fallbacks run in the order they were added until one succeeds. If all
+    /// of them fail, the call returns the error of the last one.
     pub fn with_fallback<F>(mut self, f: F) -> Self
     where
         F: FnOnce(&FallbackContext) -> Result<T, E> + Send + 'static,
     {
-        self.fallback = Some(Box::new(f));
+        self.fallbacks.push(Box::new(f));
+        self
+    }
+
+    /// Also run the fallbacks for `reason`, not only when the circuit is open
+    pub fn fallback_on(mut self, reason: FallbackReason) -> Self {
+        self.fallback_on.insert(reason);
         self
     }
+
+    /// Return `result`, or the fallbacks' answer if they handle its error
+    ///
+    /// See [`FallbackTriggers::dispatch`] for `context` and `fallback_context`.
+    pub(crate) fn finish(
+        self,
+        result: Result<T, CircuitError<E>>,
+        context: Option<FallbackContext>,
+        fallback_context: impl FnOnce(FallbackReason) -> FallbackContext,
+    ) -> Result<T, CircuitError<E>> {
+        let has_fallbacks = !self.fallbacks.is_empty();
+        match self
+            .fallback_on
+            .dispatch(has_fallbacks, result, context, fallback_context)
+        {
+            FallbackDispatch::Return(result) => result,
+            FallbackDispatch::Fallback { context, error } => self.run_fallbacks(&context, error),
+        }
+    }
+
+    /// Run the fallbacks in order until one succeeds, in place of `error`
+    fn run_fallbacks(
+        self,
+        context: &FallbackContext,
+        error: CircuitError<E>,
+    ) -> Result<T, CircuitError<E>> {
+        let mut error = error;
+        for fallback in self.fallbacks {
+            match fallback(context) {
+                Ok(value) => return Ok(value),
+                Err(fallback_error) => error = CircuitError::Execution(fallback_error),
+            }
+        }
+        Err(error)
+    }
 }
 
 /// Type alias for callable function
@@ -123,6 +410,68 @@ where
     }
 }
 
+/// Lock-free view of a circuit for the handles that share it
+///
+/// While the circuit is Closed with nothing but storage counts to update, a
+/// successful call can be recorded straight into storage without taking the
+/// circuit lock. The handles republish [`CircuitBreaker::allows_fast_path`]
+/// after every locked operation; calls that may change the state (failures,
+/// slow calls, anything outside plain Closed) still go through the lock.
+#[cfg(feature = "std")]
+pub(crate) struct FastPath {
+    enabled: AtomicBool,
+    /// Parents trip this without taking the circuit lock
+    cascade: CascadeTarget,
+    storage: Arc<dyn StorageBackend>,
+    circuit_name: String,
+    slow_call_secs: Option<f64>,
+}
+
+#[cfg(feature = "std")]
+impl FastPath {
+    pub(crate) fn new(circuit: &CircuitBreaker) -> Self {
+        Self {
+            enabled: AtomicBool::new(circuit.allows_fast_path()),
+            cascade: circuit.cascade_target(),
+            storage: Arc::clone(&circuit.context.storage),
+            circuit_name: circuit.context.name.clone(),
+            slow_call_secs: circuit.context.config.slow_call_duration_secs,
+        }
+    }
+
+    /// Whether calls may skip the circuit lock
+    pub(crate) fn enabled(&self) -> bool {
+        self.enabled.load(Ordering::Acquire) && !self.cascade.is_tripped()
+    }
+
+    /// Refresh the flag after the circuit changed under its lock
+    pub(crate) fn publish(&self, circuit: &CircuitBreaker) {
+        self.enabled
+            .store(circuit.allows_fast_path(), Ordering::Release);
+    }
+
+    pub(crate) fn start_time(&self) -> f64 {
+        self.storage.monotonic_time()
+    }
+
+    /// Record a success started at `start` unless it was slow
+    ///
+    /// Returns `None` when the call must go through the circuit instead.
+    pub(crate) fn record_success(&self, start: f64) -> Option<Result<(), StorageError>> {
+        let duration = self.storage.monotonic_time() - start;
+        if self
+            .slow_call_secs
+            .is_some_and(|threshold| duration >= threshold)
+        {
+            return None;
+        }
+        Some(
+            self.storage
+                .try_record_success(&self.circuit_name, duration),
+        )
+    }
+}
+
 pub(crate) struct CallPermit {
     _bulkhead: Option<crate::BulkheadGuard>,
     half_open_probe: bool,
@@ -165,6 +514,139 @@ impl Drop for HalfOpenProbeGuard<'_> {
     }
 }
 
+/// Count of timed-out calls whose worker threads are still running
+///
+/// Shared by every handle to a circuit, so abandoned workers are bounded per
+/// circuit rather than per call site.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default)]
+pub(crate) struct AbandonedWorkers(Arc<core::sync::atomic::AtomicUsize>);
+
+#[cfg(feature = "std")]
+impl AbandonedWorkers {
+    /// Number of abandoned workers that have not returned yet
+    pub(crate) fn running(&self) -> usize {
+        self.0.load(Ordering::Acquire)
+    }
+}
+
+/// Why a call handed to a worker thread produced no result
+#[cfg(feature = "std")]
+pub(crate) enum WorkerError {
+    /// The call ran past its timeout and its worker was abandoned
+    Timeout(crate::CallTimeout),
+    /// No worker ran the call: `max_abandoned` workers are still running, or
+    /// the thread could not be started
+    Unavailable,
+}
+
+#[cfg(feature = "std")]
+const WORKER_RUNNING: u8 = 0;
+#[cfg(feature = "std")]
+const WORKER_ABANDONED: u8 = 1;
+#[cfg(feature = "std")]
+const WORKER_DONE: u8 = 2;
+
+/// Marks a worker done when it exits, even by panic, and takes it off the
+/// abandoned count if its caller already gave up on it
+#[cfg(feature = "std")]
+struct WorkerExit {
+    state: Arc<core::sync::atomic::AtomicU8>,
+    abandoned: AbandonedWorkers,
+}
+
+#[cfg(feature = "std")]
+impl Drop for WorkerExit {
+    fn drop(&mut self) {
+        if self.state.swap(WORKER_DONE, Ordering::AcqRel) == WORKER_ABANDONED {
+            self.abandoned.0.fetch_sub(1, Ordering::AcqRel);
+        }
+    }
+}
+
+/// Run `f` on a worker thread, waiting at most `timeout_secs` for its result
+///
+/// An abandoned worker keeps the bulkhead permit until `f` returns, so hung
+/// calls still count against the concurrency limit. While `max_abandoned`
+/// abandoned workers are still running no new worker is started, which keeps
+/// a hung dependency from leaking threads. Concurrent callers may overshoot
+/// the limit by at most one worker each. A panic in `f` resumes on the
+/// calling thread.
+#[cfg(feature = "std")]
+pub(crate) fn run_on_worker<F, T, E>(
+    timeout_secs: f64,
+    abandoned: &AbandonedWorkers,
+    max_abandoned: usize,
+    permit: CallPermit,
+    f: F,
+) -> Result<Result<T, E>, WorkerError>
+where
+    F: FnOnce() -> Result<T, E> + Send + 'static,
+    T: Send + 'static,
+    E: Send + 'static,
+{
+    use core::sync::atomic::AtomicU8;
+    use std::sync::mpsc::{RecvTimeoutError, TryRecvError, sync_channel};
+
+    if abandoned.running() >= max_abandoned {
+        return Err(WorkerError::Unavailable);
+    }
+
+    let state = Arc::new(AtomicU8::new(WORKER_RUNNING));
+    let exit = WorkerExit {
+        state: Arc::clone(&state),
+        abandoned: abandoned.clone(),
+    };
+    let (sender, receiver) = sync_channel(1);
+    let worker = std::thread::Builder::new().spawn(move || {
+        let _exit = exit;
+        let result = f();
+        // Free the slot before the caller can see the result and call again
+        drop(permit);
+        let _ = sender.send(result);
+    });
+    let Ok(worker) = worker else {
+        return Err(WorkerError::Unavailable);
+    };
+
+    // A worker that exits without sending has panicked; anything else means
+    // the result is lost and the call is treated as never having run
+    let lost = |worker: std::thread::JoinHandle<()>| match worker.join() {
+        Err(panic) => std::panic::resume_unwind(panic),
+        Ok(()) => Err(WorkerError::Unavailable),
+    };
+
+    let timeout =
+        core::time::Duration::try_from_secs_f64(timeout_secs).unwrap_or(core::time::Duration::MAX);
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => Ok(result),
+        Err(RecvTimeoutError::Timeout) => {
+            // Count the worker before marking it, so its exit never sees the
+            // mark without the count
+            abandoned.0.fetch_add(1, Ordering::AcqRel);
+            if state
+                .compare_exchange(
+                    WORKER_RUNNING,
+                    WORKER_ABANDONED,
+                    Ordering::AcqRel,
+                    Ordering::Acquire,
+                )
+                .is_ok()
+            {
+                return Err(WorkerError::Timeout(crate::CallTimeout { timeout_secs }));
+            }
+
+            // The worker finished while the wait was timing out
+            abandoned.0.fetch_sub(1, Ordering::AcqRel);
+            match receiver.try_recv() {
+                Ok(result) => Ok(result),
+                Err(TryRecvError::Empty | TryRecvError::Disconnected) => lost(worker),
+            }
+        }
+        Err(RecvTimeoutError::Disconnected) => lost(worker),
+    }
+}
+
 /// Circuit breaker context - shared data across all states
 #[derive(Clone)]
 pub struct CircuitContext {
@@ -173,6 +655,7 @@ pub struct CircuitContext {
     pub storage: Arc<dyn StorageBackend>,
     pub failure_classifier: Option<Arc<dyn FailureClassifier>>,
     pub bulkhead: Option<Arc<BulkheadSemaphore>>,
+    pub recovery_guard: Option<Arc<dyn RecoveryGuard>>,
 }
 
 impl Default for CircuitContext {
@@ -183,6 +666,7 @@ impl Default for CircuitContext {
             storage: Arc::new(crate::MemoryStorage::new()),
             failure_classifier: None,
             bulkhead: None,
+            recovery_guard: None,
         }
     }
 }
@@ -201,6 +685,7 @@ impl core::fmt::Debug for CircuitContext {
                     .map(|_| "<dyn FailureClassifier>"),
             )
             .field("bulkhead", &self.bulkhead)
+            .field("recovery_guard", &self.recovery_guard)
             .finish()
     }
 }
@@ -209,6 +694,12 @@ impl core::fmt::Debug for CircuitContext {
 #[derive(Debug, Clone, Default)]
 pub struct OpenData {
     pub opened_at: f64,
+    /// Consecutive Open periods without a successful close, starting at 1;
+    /// drives the backed-off Open timeout
+    pub attempt: u32,
+    /// Open timeout for this period, with jitter drawn once when the circuit
+    /// tripped. None until drawn, in which case each check draws afresh
+    pub timeout_secs: Option<f64>,
 }
 
 /// Data specific to the HalfOpen state
@@ -216,6 +707,8 @@ pub struct OpenData {
 pub struct HalfOpenData {
     pub consecutive_successes: usize,
     pub in_flight: usize,
+    /// Attempt of the Open period this HalfOpen phase follows
+    pub attempt: u32,
 }
 
 // Define the circuit breaker state machine with dynamic mode
@@ -236,52 +729,81 @@ state_machine! {
             transition: { from: [Closed, HalfOpen], to: Open }
         }
         attempt_reset {
-            guards: [timeout_elapsed],
+            guards: [timeout_elapsed, recovery_allowed],
             transition: { from: Open, to: HalfOpen }
         }
         close {
-            guards: [should_close],
+            guards: [should_close, reset_allowed],
             transition: { from: HalfOpen, to: Closed }
         }
+        force_open {
+            transition: { from: [Closed, HalfOpen], to: Open }
+        }
+        force_close {
+            transition: { from: [Open, HalfOpen], to: Closed }
+        }
     }
 }
 
-/// Check if the failure threshold is exceeded (absolute count or rate-based).
+/// Check if the failure threshold is exceeded (absolute count, failure rate or
+/// slow-call rate).
 ///
 /// Shared by the `trip` guard for both the Closed and HalfOpen typestates; the
 /// decision depends only on the context (storage counters + config), not on the
 /// current state data.
 fn failure_threshold_exceeded(ctx: &CircuitContext) -> bool {
-    let failures = ctx
-        .storage
-        .failure_count(&ctx.name, ctx.config.failure_window_secs);
+    let slow_rate_threshold = ctx
+        .config
+        .slow_call_rate_threshold
+        .filter(|_| ctx.config.slow_call_duration_secs.is_some());
+    let needs_rates = ctx.config.failure_rate_threshold.is_some() || slow_rate_threshold.is_some();
+
+    let counts = match ctx.config.sliding_window {
+        SlidingWindow::Count(calls) => {
+            ctx.storage
+                .recent_calls(&ctx.name, calls, ctx.config.slow_call_duration_secs)
+        }
+        SlidingWindow::Time => {
+            let window = ctx.config.failure_window_secs;
+            let mut counts = CallCounts {
+                failures: ctx.storage.failure_count(&ctx.name, window),
+                ..CallCounts::default()
+            };
+            if needs_rates {
+                counts.successes = ctx.storage.success_count(&ctx.name, window);
+            }
+            if slow_rate_threshold.is_some() {
+                counts.slow_calls = ctx.storage.slow_call_count(&ctx.name, window);
+            }
+            counts
+        }
+    };
 
     // Check absolute count threshold
     if let Some(threshold) = ctx.config.failure_threshold
-        && failures >= threshold
+        && counts.failures >= threshold
     {
         return true;
     }
 
-    // Check rate-based threshold
-    if let Some(rate_threshold) = ctx.config.failure_rate_threshold {
-        let successes = ctx
-            .storage
-            .success_count(&ctx.name, ctx.config.failure_window_secs);
-        let total = failures + successes;
+    // Only evaluate rates if we have minimum calls
+    let total = counts.total();
+    if !needs_rates || total < ctx.config.minimum_calls || total == 0 {
+        return false;
+    }
 
-        // Only evaluate rate if we have minimum calls
-        if total >= ctx.config.minimum_calls {
-            let failure_rate = if total > 0 {
-                failures as f64 / total as f64
-            } else {
-                0.0
-            };
+    // Check rate-based threshold
+    if let Some(rate_threshold) = ctx.config.failure_rate_threshold
+        && counts.failures as f64 / total as f64 >= rate_threshold
+    {
+        return true;
+    }
 
-            if failure_rate >= rate_threshold {
-                return true;
-            }
-        }
+    // Check slow-call rate threshold
+    if let Some(rate_threshold) = slow_rate_threshold
+        && counts.slow_calls as f64 / total as f64 >= rate_threshold
+    {
+        return true;
     }
 
     false
@@ -308,6 +830,17 @@ impl Circuit<HalfOpen> {
         };
         data.consecutive_successes >= ctx.config.success_threshold
     }
+
+    /// Check if the recovery guard lets the circuit close
+    fn reset_allowed(&self, ctx: &CircuitContext) -> bool {
+        let attempt = self.state_data_half_open().map_or(1, |data| data.attempt);
+        ctx.recovery_guard.as_ref().is_none_or(|guard| {
+            guard.reset_allowed(&RecoveryContext {
+                circuit_name: &ctx.name,
+                attempt,
+            })
+        })
+    }
 }
 
 impl Circuit<Open> {
@@ -318,33 +851,115 @@ impl Circuit<Open> {
         };
         let current_time = ctx.storage.monotonic_time();
         let elapsed = current_time - data.opened_at;
-
-        // Apply jitter using chrono-machines if jitter_factor > 0
-        let timeout_secs = if ctx.config.jitter_factor > 0.0 {
-            let policy = chrono_machines::Policy {
-                max_attempts: 1,
-                base_delay_ms: (ctx.config.half_open_timeout_secs * 1000.0) as u64,
-                multiplier: 1.0,
-                max_delay_ms: (ctx.config.half_open_timeout_secs * 1000.0) as u64,
-            };
-            #[cfg(feature = "std")]
-            let timeout_ms = policy.calculate_delay(1, ctx.config.jitter_factor);
-            #[cfg(not(feature = "std"))]
-            let timeout_ms = policy.base_delay_ms;
-            (timeout_ms as f64) / 1000.0
-        } else {
-            ctx.config.half_open_timeout_secs
-        };
+        let timeout_secs = data
+            .timeout_secs
+            .unwrap_or_else(|| open_timeout_secs(&ctx.config, data.attempt));
 
         elapsed >= timeout_secs
     }
+
+    /// Check if the recovery guard lets the circuit probe
+    fn recovery_allowed(&self, ctx: &CircuitContext) -> bool {
+        let attempt = self.state_data_open().map_or(1, |data| data.attempt);
+        ctx.recovery_guard.as_ref().is_none_or(|guard| {
+            guard.recovery_allowed(&RecoveryContext {
+                circuit_name: &ctx.name,
+                attempt,
+            })
+        })
+    }
+}
+
+/// Open timeout for the given consecutive Open attempt
+///
+/// Backs off exponentially with a chrono-machines `Policy`, then applies
+/// jitter if `jitter_factor > 0`.
+fn open_timeout_secs(config: &Config, attempt: u32) -> f64 {
+    let attempt = attempt.clamp(1, u32::from(u8::MAX)) as u8;
+    let max_secs = config.max_half_open_timeout_secs.unwrap_or(f64::INFINITY);
+    let policy = chrono_machines::Policy {
+        max_attempts: u8::MAX,
+        base_delay_ms: (config.half_open_timeout_secs * 1000.0) as u64,
+        multiplier: config.half_open_backoff_multiplier,
+        max_delay_ms: (max_secs * 1000.0) as u64,
+    };
+
+    // Apply jitter using chrono-machines if jitter_factor > 0
+    #[cfg(feature = "std")]
+    if config.jitter_factor > 0.0 {
+        return policy.calculate_delay(attempt, config.jitter_factor) as f64 / 1000.0;
+    }
+
+    // Without jitter, stay exact to the configured (sub-millisecond) seconds
+    let mut timeout_secs = config.half_open_timeout_secs;
+    for _ in 1..attempt {
+        if timeout_secs >= max_secs {
+            break;
+        }
+        timeout_secs *= policy.multiplier;
+    }
+    timeout_secs.min(max_secs)
+}
+
+/// Build the state machine from the status persisted in storage, if any.
+///
+/// A restored Open circuit keeps its original `opened_at`, backoff attempt and
+/// jittered timeout, so a restarted worker waits out the remaining (backed-off)
+/// timeout instead of probing immediately or starting over from the base.
+fn restore_machine(context: &CircuitContext) -> DynamicCircuit {
+    let Some(status) = context.storage.get_status(&context.name) else {
+        return DynamicCircuit::new(context.clone());
+    };
+
+    match status.state {
+        CircuitState::Closed => DynamicCircuit::new(context.clone()),
+        CircuitState::Open => {
+            let mut machine = DynamicCircuit::new_init_state(context.clone(), CircuitState::Open);
+            // A reading from the future means the clock origin moved; treat the
+            // circuit as freshly opened rather than open indefinitely.
+            let now = context.storage.monotonic_time();
+            let opened_at = status.opened_at.unwrap_or(now).min(now);
+            let attempt = status.attempt.max(1);
+            let timeout_secs = status
+                .timeout_secs
+                .unwrap_or_else(|| open_timeout_secs(&context.config, attempt));
+            let _ = machine.set_open_data(OpenData {
+                opened_at,
+                attempt,
+                timeout_secs: Some(timeout_secs),
+            });
+            machine
+        }
+        CircuitState::HalfOpen => {
+            let mut machine =
+                DynamicCircuit::new_init_state(context.clone(), CircuitState::HalfOpen);
+            let _ = machine.set_half_open_data(HalfOpenData {
+                attempt: status.attempt.max(1),
+                ..HalfOpenData::default()
+            });
+            machine
+        }
+    }
 }
 
 /// Circuit breaker public API
+///
+/// Dropping a circuit releases the dependents it holds open.
 pub struct CircuitBreaker {
     machine: DynamicCircuit,
     context: CircuitContext,
     callbacks: Callbacks,
+    /// Last error reported by storage, cleared once it answers again
+    storage_error: Option<StorageError>,
+    /// Operator override, if any
+    override_mode: Option<CircuitOverride>,
+    /// Held by parent circuits to force this one open
+    cascade: CascadeTarget,
+    /// Dependents forced open while this circuit is open
+    cascades_to: Vec<CascadeTarget>,
+    /// Timed-out calls whose worker threads are still running
+    #[cfg(feature = "std")]
+    abandoned_workers: AbandonedWorkers,
 }
 
 impl CircuitBreaker {
@@ -356,28 +971,35 @@ impl CircuitBreaker {
             ..CircuitContext::default()
         };
 
-        let machine = DynamicCircuit::new(context.clone());
-        let callbacks = Callbacks::new();
-
-        Self {
-            machine,
-            context,
-            callbacks,
-        }
+        Self::with_context_and_callbacks(context, Callbacks::new(), Vec::new())
     }
 
-    /// Create a circuit breaker with custom context and callbacks (used by builder)
+    /// Create a circuit breaker with custom context, callbacks and cascade
+    /// dependents (used by builder)
     pub(crate) fn with_context_and_callbacks(
         context: CircuitContext,
         callbacks: Callbacks,
+        cascades_to: Vec<CascadeTarget>,
     ) -> Self {
-        let machine = DynamicCircuit::new(context.clone());
+        let machine = restore_machine(&context);
+        let cascade = CascadeTarget::new(context.name.clone(), callbacks.clone());
 
-        Self {
+        let circuit = Self {
             machine,
             context,
             callbacks,
+            storage_error: None,
+            override_mode: None,
+            cascade,
+            cascades_to,
+            #[cfg(feature = "std")]
+            abandoned_workers: AbandonedWorkers::default(),
+        };
+        // A circuit restored as Open holds its dependents open from the start
+        if circuit.is_open() {
+            circuit.cascade_open();
         }
+        circuit
     }
 
     /// Create a new circuit breaker builder
@@ -396,29 +1018,158 @@ impl CircuitBreaker {
     {
         let (f, options) = input.into_call_options();
 
-        match self.prepare_call()? {
-            CallGate::Execute(permit) => self.execute_call(permit, f),
-            CallGate::Open {
+        let (result, context) = match self.prepare_call() {
+            Ok(CallGate::Execute(permit)) => (self.execute_call(permit, f), None),
+            Ok(CallGate::Open {
                 _permit: permit,
                 context,
-            } => {
+            }) => {
                 // Release the bulkhead permit before the fallback runs so a slow
                 // fallback doesn't occupy a concurrency slot (matches async path).
                 drop(permit);
+                (Err(context.clone().into_open_error()), Some(context))
+            }
+            Err(error) => (Err(error), None),
+        };
 
-                if let Some(fallback) = options.fallback {
-                    return fallback(&context).map_err(CircuitError::Execution);
-                }
+        options.finish(result, context, |reason| self.fallback_context(reason))
+    }
 
-                Err(CircuitError::Open {
-                    circuit: context.circuit_name,
-                    opened_at: context.opened_at,
-                })
+    /// Execute a fallible operation, giving up after `call_timeout_secs`
+    ///
+    /// The operation runs on a worker thread. If it hasn't returned when the
+    /// timeout elapses, the call is recorded as a failure (classified as a
+    /// [`CallTimeout`]) and returns [`CircuitError::Timeout`]. The worker is
+    /// left to finish on its own and holds its bulkhead permit until then.
+    /// Once `max_abandoned_calls` such workers are still running, new calls
+    /// are refused with [`CircuitError::TimeoutWorkersExhausted`] instead of
+    /// starting another thread. Without a configured timeout this is the same as
+    /// [`call`](Self::call).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use breaker_machines::{CircuitBreaker, CircuitError};
+    ///
+    /// let mut circuit = CircuitBreaker::builder("legacy")
+    ///     .call_timeout_secs(0.01)
+    ///     .build();
+    ///
+    /// let result = circuit.call_with_timeout(|| {
+    ///     std::thread::sleep(std::time::Duration::from_secs(1));
+    ///     Ok::<_, String>("too late")
+    /// });
+    /// assert!(matches!(result, Err(CircuitError::Timeout { .. })));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn call_with_timeout<F, T, E>(&mut self, f: F) -> Result<T, CircuitError<E>>
+    where
+        F: FnOnce() -> Result<T, E> + Send + 'static,
+        T: Send + 'static,
+        E: Send + 'static,
+    {
+        self.call_with_timeout_and_options(f, CallOptions::new())
+    }
+
+    /// [`call_with_timeout`](Self::call_with_timeout) with fallbacks
+    ///
+    /// A timed-out call is handed to the fallbacks when the options include
+    /// [`FallbackReason::Timeout`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use breaker_machines::{CallOptions, CircuitBreaker, FallbackReason};
+    ///
+    /// let mut circuit = CircuitBreaker::builder("legacy")
+    ///     .call_timeout_secs(0.01)
+    ///     .build();
+    ///
+    /// let options = CallOptions::new()
+    ///     .with_fallback(|_ctx| Ok("cached"))
+    ///     .fallback_on(FallbackReason::Timeout);
+    /// let result = circuit.call_with_timeout_and_options(
+    ///     || {
+    ///         std::thread::sleep(std::time::Duration::from_secs(1));
+    ///         Ok::<_, String>("too late")
+    ///     },
+    ///     options,
+    /// );
+    /// assert_eq!(result.unwrap(), "cached");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn call_with_timeout_and_options<F, T, E>(
+        &mut self,
+        f: F,
+        options: CallOptions<T, E>,
+    ) -> Result<T, CircuitError<E>>
+    where
+        F: FnOnce() -> Result<T, E> + Send + 'static,
+        T: Send + 'static,
+        E: Send + 'static,
+    {
+        let Some(timeout_secs) = self.context.config.call_timeout_secs else {
+            return self.call((f, options));
+        };
+
+        let (result, context) = match self.prepare_call() {
+            Ok(CallGate::Execute(permit)) => {
+                (self.execute_timed_call(timeout_secs, permit, f), None)
             }
-        }
+            Ok(CallGate::Open {
+                _permit: permit,
+                context,
+            }) => {
+                drop(permit);
+                (Err(context.clone().into_open_error()), Some(context))
+            }
+            Err(error) => (Err(error), None),
+        };
+
+        options.finish(result, context, |reason| self.fallback_context(reason))
+    }
+
+    /// Run an admitted call on a worker thread and record its outcome
+    #[cfg(feature = "std")]
+    fn execute_timed_call<F, T, E>(
+        &mut self,
+        timeout_secs: f64,
+        permit: CallPermit,
+        f: F,
+    ) -> Result<T, CircuitError<E>>
+    where
+        F: FnOnce() -> Result<T, E> + Send + 'static,
+        T: Send + 'static,
+        E: Send + 'static,
+    {
+        let half_open_probe = permit.half_open_probe();
+        let start = self.start_time();
+        let abandoned = self.abandoned_workers.clone();
+        let max_abandoned = self.context.config.max_abandoned_calls;
+
+        // As in `execute_call`, the guard releases the probe slot if the
+        // operation's panic resumes here
+        let result = {
+            let mut probe_guard = HalfOpenProbeGuard {
+                circuit: self,
+                armed: half_open_probe,
+            };
+            let result = run_on_worker(timeout_secs, &abandoned, max_abandoned, permit, f);
+            probe_guard.disarm();
+            result
+        };
+
+        self.complete_timed_call(start, result, half_open_probe)
     }
 
     pub(crate) fn prepare_call<E>(&mut self) -> Result<CallGate, CircuitError<E>> {
+        if self.is_disabled() {
+            return Ok(CallGate::Execute(CallPermit {
+                _bulkhead: None,
+                half_open_probe: false,
+            }));
+        }
+
         // Try to acquire bulkhead permit if configured
         let permit = if let Some(bulkhead) = &self.context.bulkhead {
             match bulkhead.try_acquire() {
@@ -438,28 +1189,26 @@ impl CircuitBreaker {
             half_open_probe: false,
         };
 
-        // Check for timeout-based Open -> HalfOpen transition
-        if self.machine.current_state() == CircuitState::Open {
-            let _ = self.machine.handle(CircuitEvent::AttemptReset);
-            if self.machine.current_state() == CircuitState::HalfOpen {
-                self.callbacks.trigger_half_open(&self.context.name);
-            }
+        // An override pins the state, whatever other processes decide
+        if self.context.storage.shares_state() && self.override_mode.is_none() {
+            self.sync_shared_status();
+        }
+        self.check_storage()?;
+
+        self.try_attempt_reset();
+
+        // Probes that succeeded while the recovery guard held the circuit
+        // HalfOpen close it as soon as the guard allows
+        if self.machine.current_state() == CircuitState::HalfOpen
+            && self.context.recovery_guard.is_some()
+        {
+            self.try_close();
         }
 
         // Handle based on current state
         match self.machine.current_state() {
-            CircuitState::Open => {
-                let opened_at = self.machine.open_data().map(|d| d.opened_at).unwrap_or(0.0);
-
-                Ok(CallGate::Open {
-                    _permit: permit,
-                    context: FallbackContext {
-                        circuit_name: self.context.name.clone(),
-                        opened_at,
-                        state: "Open",
-                    },
-                })
-            }
+            _ if self.cascade_blocked() => Ok(self.open_gate(permit)),
+            CircuitState::Open => Ok(self.open_gate(permit)),
             CircuitState::HalfOpen => {
                 // Check if we've reached the success threshold
                 if let Some(data) = self.machine.half_open_data_mut() {
@@ -479,10 +1228,54 @@ impl CircuitBreaker {
         }
     }
 
-    fn execute_call<T, E: 'static>(
+    /// Move an Open circuit to HalfOpen once its timeout has elapsed
+    fn try_attempt_reset(&mut self) {
+        if self.machine.current_state() != CircuitState::Open || self.held_open() {
+            return;
+        }
+        let attempt = self.machine.open_data().map_or(1, |d| d.attempt);
+        let _ = self.machine.handle(CircuitEvent::AttemptReset);
+        if let Some(data) = self.machine.half_open_data_mut() {
+            data.attempt = attempt;
+        }
+        if self.machine.current_state() == CircuitState::HalfOpen {
+            self.persist_status();
+            self.callbacks.trigger_half_open(&self.context.name);
+        }
+    }
+
+    /// Reject a call, handing the fallback the timing of the Open period
+    fn open_gate(&self, permit: CallPermit) -> CallGate {
+        CallGate::Open {
+            _permit: permit,
+            context: self.fallback_context(FallbackReason::Open),
+        }
+    }
+
+    /// Context for fallbacks run for `reason`
+    pub(crate) fn fallback_context(&self, reason: FallbackReason) -> FallbackContext {
+        let (opened_at, attempt) = self
+            .machine
+            .open_data()
+            .map_or((0.0, 1), |d| (d.opened_at, d.attempt));
+        let (timeout_secs, retry_after_secs) = self.open_timing().unwrap_or_default();
+
+        FallbackContext {
+            circuit_name: self.context.name.clone(),
+            opened_at,
+            attempt,
+            retry_after_secs,
+            timeout_secs,
+            state: self.state_name(),
+            reason,
+        }
+    }
+
+    /// Run an admitted call and record its outcome
+    pub(crate) fn execute_call<T, E: 'static>(
         &mut self,
         permit: CallPermit,
-        f: Box<dyn FnOnce() -> Result<T, E>>,
+        f: impl FnOnce() -> Result<T, E>,
     ) -> Result<T, CircuitError<E>> {
         let half_open_probe = permit.half_open_probe();
         let start = self.start_time();
@@ -514,32 +1307,82 @@ impl CircuitBreaker {
         result: Result<T, E>,
         half_open_probe: bool,
     ) -> Result<T, CircuitError<E>> {
-        if half_open_probe {
-            self.release_half_open_probe();
-        }
-
+        let duration = self.context.storage.monotonic_time() - start;
+        let should_trip = match &result {
+            Ok(_) => false,
+            Err(e) => self.should_trip_on(e, duration),
+        };
+        self.complete_classified_call(duration, result, should_trip, half_open_probe)
+    }
+
+    /// Record the outcome of a call run with [`run_on_worker`]
+    #[cfg(feature = "std")]
+    pub(crate) fn complete_timed_call<T, E: 'static>(
+        &mut self,
+        start: f64,
+        result: Result<Result<T, E>, WorkerError>,
+        half_open_probe: bool,
+    ) -> Result<T, CircuitError<E>> {
+        match result {
+            Ok(result) => self.complete_call(start, result, half_open_probe),
+            Err(WorkerError::Timeout(timeout)) => self
+                .complete_call(start, Err::<T, _>(timeout), half_open_probe)
+                .map_err(|_| timeout.into_error(self.context.name.clone())),
+            // The operation never ran, so there is no outcome to record
+            Err(WorkerError::Unavailable) => {
+                if half_open_probe {
+                    self.release_half_open_probe();
+                }
+                Err(CircuitError::TimeoutWorkersExhausted {
+                    circuit: self.context.name.clone(),
+                    limit: self.context.config.max_abandoned_calls,
+                })
+            }
+        }
+    }
+
+    /// Timed-out calls of this circuit whose workers are still running
+    #[cfg(feature = "std")]
+    pub(crate) fn abandoned_workers(&self) -> &AbandonedWorkers {
+        &self.abandoned_workers
+    }
+
+    /// Whether an error counts as a failure, according to the failure classifier
+    pub(crate) fn should_trip_on<E: 'static>(&self, error: &E, duration: f64) -> bool {
+        if let Some(classifier) = &self.context.failure_classifier {
+            let ctx = crate::classifier::FailureContext {
+                circuit_name: &self.context.name,
+                error: error as &dyn core::any::Any,
+                duration,
+            };
+            classifier.should_trip(&ctx)
+        } else {
+            // No classifier - default behavior is to trip on all errors
+            true
+        }
+    }
+
+    /// Record an outcome whose error was already classified by [`Self::should_trip_on`]
+    pub(crate) fn complete_classified_call<T, E>(
+        &mut self,
+        duration: f64,
+        result: Result<T, E>,
+        should_trip: bool,
+        half_open_probe: bool,
+    ) -> Result<T, CircuitError<E>> {
+        if half_open_probe {
+            self.release_half_open_probe();
+        }
+        if self.is_disabled() {
+            return result.map_err(CircuitError::Execution);
+        }
+
         match result {
             Ok(val) => {
-                let duration = self.context.storage.monotonic_time() - start;
                 self.record_success_and_maybe_close(duration);
                 Ok(val)
             }
             Err(e) => {
-                let duration = self.context.storage.monotonic_time() - start;
-
-                // Check if this error should trip the circuit using failure classifier
-                let should_trip = if let Some(classifier) = &self.context.failure_classifier {
-                    let ctx = crate::classifier::FailureContext {
-                        circuit_name: &self.context.name,
-                        error: &e as &dyn core::any::Any,
-                        duration,
-                    };
-                    classifier.should_trip(&ctx)
-                } else {
-                    // No classifier - default behavior is to trip on all errors
-                    true
-                };
-
                 // Only record failure and try to trip if the classifier says we should
                 if should_trip {
                     self.record_failure_and_maybe_trip(duration);
@@ -557,32 +1400,51 @@ impl CircuitBreaker {
     }
 
     /// Record a successful operation and drive HalfOpen -> Closed transitions
+    ///
+    /// A slow success may instead trip the circuit when a slow-call rate
+    /// threshold is configured.
     pub fn record_success_and_maybe_close(&mut self, duration: f64) {
-        self.context
+        let result = self
+            .context
             .storage
-            .record_success(&self.context.name, duration);
+            .try_record_success(&self.context.name, duration);
+        self.track_storage(result);
+
+        if self.record_if_slow(duration)
+            && self.context.config.slow_call_rate_threshold.is_some()
+            && self.check_and_trip()
+        {
+            return;
+        }
 
         if self.machine.current_state() == CircuitState::HalfOpen {
             if let Some(data) = self.machine.half_open_data_mut() {
                 data.consecutive_successes += 1;
             }
+            self.try_close();
+        }
+    }
 
-            if self.machine.handle(CircuitEvent::Close).is_ok() {
-                self.callbacks.trigger_close(&self.context.name);
-            }
+    /// Close a HalfOpen circuit if enough probes succeeded
+    fn try_close(&mut self) {
+        if self.machine.handle(CircuitEvent::Close).is_ok() {
+            self.persist_status();
+            self.callbacks.trigger_close(&self.context.name);
+            self.cascade_close();
         }
     }
 
     /// Record a failed operation and attempt to trip the circuit
     pub fn record_failure_and_maybe_trip(&mut self, duration: f64) {
-        self.context
+        let result = self
+            .context
             .storage
-            .record_failure(&self.context.name, duration);
+            .try_record_failure(&self.context.name, duration);
+        self.track_storage(result);
+        self.record_if_slow(duration);
 
-        let result = self.machine.handle(CircuitEvent::Trip);
-        if result.is_ok() {
-            self.mark_open();
-        } else if self.machine.current_state() == CircuitState::HalfOpen
+        if !self.check_and_trip()
+            && self.machine.current_state() == CircuitState::HalfOpen
             && let Some(data) = self.machine.half_open_data_mut()
         {
             data.consecutive_successes = 0;
@@ -594,6 +1456,11 @@ impl CircuitBreaker {
         self.context
             .storage
             .record_success(&self.context.name, duration);
+        if self.is_slow(duration) {
+            self.context
+                .storage
+                .record_slow_call(&self.context.name, duration);
+        }
     }
 
     /// Record a failed operation (for manual tracking)
@@ -601,53 +1468,397 @@ impl CircuitBreaker {
         self.context
             .storage
             .record_failure(&self.context.name, duration);
+        if self.is_slow(duration) {
+            self.context
+                .storage
+                .record_slow_call(&self.context.name, duration);
+        }
+    }
+
+    /// Whether a call of this duration counts as slow
+    pub(crate) fn is_slow(&self, duration: f64) -> bool {
+        self.context
+            .config
+            .slow_call_duration_secs
+            .is_some_and(|threshold| duration >= threshold)
+    }
+
+    /// Count the call as slow if it took too long; returns whether it did
+    fn record_if_slow(&mut self, duration: f64) -> bool {
+        if !self.is_slow(duration) {
+            return false;
+        }
+        let result = self
+            .context
+            .storage
+            .try_record_slow_call(&self.context.name, duration);
+        self.track_storage(result);
+        true
     }
 
     /// Check failure threshold and attempt to trip the circuit
     /// This should be called after record_failure() when not using call()
+    ///
+    /// Never trips while an override is set.
     pub fn check_and_trip(&mut self) -> bool {
+        if self.override_mode.is_some() {
+            return false;
+        }
+        // A failed HalfOpen probe extends the backoff; tripping from Closed starts over
+        let attempt = self
+            .machine
+            .half_open_data()
+            .map_or(1, |d| d.attempt.saturating_add(1));
         if self.machine.handle(CircuitEvent::Trip).is_ok() {
-            self.mark_open();
+            self.mark_open(attempt);
             true
         } else {
             false
         }
     }
 
-    /// Check if circuit is open
+    #[cfg(feature = "std")]
+    pub(crate) fn config(&self) -> &Config {
+        &self.context.config
+    }
+
+    /// Name the circuit was created with
+    pub fn name(&self) -> &str {
+        &self.context.name
+    }
+
+    /// Check if circuit is open, including when a parent's cascade holds it open
     pub fn is_open(&self) -> bool {
-        self.machine.current_state() == CircuitState::Open
+        self.machine.current_state() == CircuitState::Open || self.cascade_blocked()
+    }
+
+    /// Seconds until an Open circuit lets a probe through, or None if it isn't Open
+    ///
+    /// Zero once the timeout has elapsed; the circuit moves to HalfOpen on
+    /// the next call.
+    pub fn retry_after(&self) -> Option<f64> {
+        self.open_timing().map(|(_, remaining)| remaining)
+    }
+
+    /// Timeout of the current Open period and the seconds left of it
+    ///
+    /// A circuit forced open, by an operator or a cascade, never lets a probe
+    /// through, so no time is left.
+    fn open_timing(&self) -> Option<(f64, f64)> {
+        let Some(data) = self.machine.open_data() else {
+            return self
+                .cascade_blocked()
+                .then(|| (open_timeout_secs(&self.context.config, 1), f64::INFINITY));
+        };
+        let timeout_secs = data
+            .timeout_secs
+            .unwrap_or_else(|| open_timeout_secs(&self.context.config, data.attempt));
+        if self.held_open() {
+            return Some((timeout_secs, f64::INFINITY));
+        }
+        let elapsed = self.context.storage.monotonic_time() - data.opened_at;
+        Some((timeout_secs, (timeout_secs - elapsed).max(0.0)))
     }
 
     /// Check if circuit is closed
     pub fn is_closed(&self) -> bool {
-        self.machine.current_state() == CircuitState::Closed
+        self.machine.current_state() == CircuitState::Closed && !self.cascade_blocked()
     }
 
-    /// Get current state name
+    /// Get current state name, or the name of the override if one is set
+    ///
+    /// A circuit held open by a parent's cascade reports `"CascadeOpen"`.
     pub fn state_name(&self) -> &'static str {
-        self.machine.current_state().name()
+        match self.override_mode {
+            Some(mode) => mode.name(),
+            None if self.cascade.is_tripped() => "CascadeOpen",
+            None => self.machine.current_state().name(),
+        }
+    }
+
+    /// Handle for parent circuits to force this one open, see
+    /// [`CircuitBuilder::cascades_to`](crate::CircuitBuilder::cascades_to)
+    pub fn cascade_target(&self) -> CascadeTarget {
+        self.cascade.clone()
+    }
+
+    /// Name of the parent circuit whose cascade holds this circuit open
+    pub fn cascade_source(&self) -> Option<String> {
+        self.cascade.source()
+    }
+
+    /// Drop every parent's cascade latch, e.g. when a parent is known to be
+    /// gone or recovered without closing through this process
+    ///
+    /// A parent that is still open latches the circuit again the next time it
+    /// trips.
+    pub fn clear_cascade(&self) {
+        self.cascade.release_all();
+    }
+
+    /// Whether a parent's cascade rejects calls; operator overrides take
+    /// precedence over cascades
+    fn cascade_blocked(&self) -> bool {
+        self.override_mode.is_none() && self.cascade.is_tripped()
+    }
+
+    /// Whether the circuit is kept open regardless of its timeout
+    fn held_open(&self) -> bool {
+        self.override_mode == Some(CircuitOverride::ForcedOpen) || self.cascade_blocked()
+    }
+
+    /// Force dependents open on behalf of this circuit
+    fn cascade_open(&self) {
+        for target in &self.cascades_to {
+            target.trip(&self.context.name);
+        }
+    }
+
+    /// Drop a circuit that was never put to use without releasing its
+    /// dependents, whose latches belong to a live circuit of the same name
+    #[cfg(feature = "std")]
+    pub(crate) fn discard(mut self) {
+        self.cascades_to.clear();
+    }
+
+    /// Let dependents recover once this circuit has closed
+    fn cascade_close(&self) {
+        for target in &self.cascades_to {
+            target.release(&self.context.name);
+        }
+    }
+
+    /// Operator override currently in effect
+    pub fn override_mode(&self) -> Option<CircuitOverride> {
+        self.override_mode
+    }
+
+    /// Open the circuit and reject every call until the override is released
+    pub fn force_open(&mut self) {
+        self.override_mode = Some(CircuitOverride::ForcedOpen);
+        if self.machine.handle(CircuitEvent::ForceOpen).is_ok() {
+            self.mark_open(1);
+        }
+    }
+
+    /// Close the circuit and keep it closed, whatever the thresholds say
+    ///
+    /// Outcomes are still recorded, so [`stats`](Self::stats) stays accurate.
+    pub fn force_close(&mut self) {
+        self.close_with_override(CircuitOverride::ForcedClosed);
     }
 
-    /// Clear all events and reset circuit to Closed state
+    /// Close the circuit and pass calls straight through
+    ///
+    /// Nothing is recorded and the bulkhead is not enforced until the override
+    /// is released.
+    pub fn disable(&mut self) {
+        self.close_with_override(CircuitOverride::Disabled);
+    }
+
+    /// Lift the override and let the thresholds drive the circuit again
+    ///
+    /// A circuit released from [`force_open`](Self::force_open) stays Open
+    /// until its timeout, counted from when it was forced open, elapses; if it
+    /// already has, the circuit moves to HalfOpen right away. With shared
+    /// storage, transitions other processes made during the override are
+    /// adopted first. Either way the resulting state is persisted and its
+    /// transition callback fires, as for any other transition.
+    pub fn release_override(&mut self) {
+        if self.override_mode.take().is_none() {
+            return;
+        }
+        if self.context.storage.shares_state() {
+            self.sync_shared_status();
+        }
+        self.try_attempt_reset();
+        self.persist_status();
+    }
+
+    /// Whether a successful call can bypass the state machine: the circuit is
+    /// plainly Closed, admits calls without a bulkhead or status read, and a
+    /// fast success would change nothing but the storage counts
+    #[cfg(feature = "std")]
+    pub(crate) fn allows_fast_path(&self) -> bool {
+        self.machine.current_state() == CircuitState::Closed
+            && self.override_mode.is_none()
+            && self.context.bulkhead.is_none()
+            && self.storage_error.is_none()
+            && !self.context.storage.shares_state()
+            && !self.cascade.is_tripped()
+    }
+
+    pub(crate) fn is_disabled(&self) -> bool {
+        self.override_mode == Some(CircuitOverride::Disabled)
+    }
+
+    fn close_with_override(&mut self, mode: CircuitOverride) {
+        self.override_mode = Some(mode);
+        if self.machine.handle(CircuitEvent::ForceClose).is_ok() {
+            self.persist_status();
+            self.callbacks.trigger_close(&self.context.name);
+        }
+        // Dependents recover even if the circuit was only held open
+        self.cascade_close();
+    }
+
+    /// Snapshot of the state and the counts within the sliding window
+    pub fn stats(&self) -> CircuitStats {
+        let storage = &self.context.storage;
+        let name = &self.context.name;
+        let config = &self.context.config;
+        let counts = match config.sliding_window {
+            SlidingWindow::Count(calls) => {
+                storage.recent_calls(name, calls, config.slow_call_duration_secs)
+            }
+            SlidingWindow::Time => CallCounts {
+                successes: storage.success_count(name, config.failure_window_secs),
+                failures: storage.failure_count(name, config.failure_window_secs),
+                slow_calls: storage.slow_call_count(name, config.failure_window_secs),
+            },
+        };
+        CircuitStats {
+            state: self.machine.current_state(),
+            failure_count: counts.failures,
+            success_count: counts.successes,
+            slow_call_count: counts.slow_calls,
+            opened_at: self.machine.open_data().map(|d| d.opened_at),
+        }
+    }
+
+    /// Clear all events, lift any override and reset circuit to Closed state
     pub fn reset(&mut self) {
-        self.context.storage.clear(&self.context.name);
+        let result = self.context.storage.try_clear(&self.context.name);
+        self.track_storage(result);
         // Recreate machine in Closed state
         self.machine = DynamicCircuit::new(self.context.clone());
+        self.override_mode = None;
+        self.cascade_close();
+        self.persist_status();
     }
 
-    /// Apply Open-state bookkeeping (timestamp + callback)
-    fn mark_open(&mut self) {
+    /// Apply Open-state bookkeeping (timestamp, attempt, persisted status, callback)
+    fn mark_open(&mut self, attempt: u32) {
         if let Some(data) = self.machine.open_data_mut() {
             data.opened_at = self.context.storage.monotonic_time();
+            data.attempt = attempt;
+            data.timeout_secs = Some(open_timeout_secs(&self.context.config, attempt));
         }
+        self.persist_status();
         self.callbacks.trigger_open(&self.context.name);
+        self.cascade_open();
+    }
+
+    /// Last error reported by the storage backend, if it hasn't recovered since
+    pub fn storage_error(&self) -> Option<&StorageError> {
+        self.storage_error.as_ref()
+    }
+
+    /// Remember whether the last storage operation failed
+    pub(crate) fn track_storage<T>(&mut self, result: Result<T, StorageError>) -> Option<T> {
+        match result {
+            Ok(value) => {
+                self.storage_error = None;
+                Some(value)
+            }
+            Err(error) => {
+                self.storage_error = Some(error);
+                None
+            }
+        }
+    }
+
+    /// Under [`StorageFailurePolicy::FailClosed`], reject calls while storage
+    /// is failing. A failing backend is probed with a status read each call.
+    fn check_storage<E>(&mut self) -> Result<(), CircuitError<E>> {
+        if self.context.config.storage_failure_policy != StorageFailurePolicy::FailClosed {
+            return Ok(());
+        }
+
+        // Shared storage was just probed by `sync_shared_status`
+        if self.storage_error.is_some() && !self.context.storage.shares_state() {
+            let result = self.context.storage.try_get_status(&self.context.name);
+            self.track_storage(result);
+        }
+
+        match &self.storage_error {
+            Some(error) => Err(CircuitError::Storage {
+                circuit: self.context.name.clone(),
+                error: error.clone(),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Adopt Open/Closed transitions made by other processes sharing the storage
+    fn sync_shared_status(&mut self) {
+        let result = self.context.storage.try_get_status(&self.context.name);
+        let Some(status) = self.track_storage(result).flatten() else {
+            return;
+        };
+
+        match (status.state, self.machine.current_state()) {
+            (CircuitState::Open, CircuitState::Open) => {
+                // Another process re-opened it more recently; wait from then,
+                // for as long as it decided
+                if let (Some(remote), Some(data)) = (status.opened_at, self.machine.open_data_mut())
+                    && remote > data.opened_at
+                {
+                    data.opened_at = remote;
+                    data.attempt = status.attempt.max(data.attempt);
+                    if status.timeout_secs.is_some() {
+                        data.timeout_secs = status.timeout_secs;
+                    }
+                }
+            }
+            (CircuitState::Open, _) => {
+                self.machine = restore_machine(&self.context);
+                self.callbacks.trigger_open(&self.context.name);
+                self.cascade_open();
+            }
+            (CircuitState::Closed, CircuitState::Open | CircuitState::HalfOpen) => {
+                self.machine = DynamicCircuit::new(self.context.clone());
+                self.callbacks.trigger_close(&self.context.name);
+                self.cascade_close();
+            }
+            _ => {}
+        }
+    }
+
+    /// Write the current state to storage so a new instance can resume it
+    fn persist_status(&mut self) {
+        let status = match self.machine.current_state() {
+            CircuitState::Open => {
+                let data = self.machine.open_data().cloned().unwrap_or_default();
+                let status = CircuitStatus::open(data.opened_at).with_attempt(data.attempt);
+                match data.timeout_secs {
+                    Some(timeout_secs) => status.with_timeout_secs(timeout_secs),
+                    None => status,
+                }
+            }
+            CircuitState::HalfOpen => CircuitStatus::new(CircuitState::HalfOpen)
+                .with_attempt(self.machine.half_open_data().map_or(0, |data| data.attempt)),
+            state => CircuitStatus::new(state),
+        };
+        let result = self
+            .context
+            .storage
+            .try_set_status(&self.context.name, status);
+        self.track_storage(result);
+    }
+}
+
+impl Drop for CircuitBreaker {
+    fn drop(&mut self) {
+        // A dropped parent can never close, so it must not hold dependents
+        self.cascade_close();
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::time::test_support::ManualClock;
 
     #[test]
     fn test_circuit_breaker_creation() {
@@ -705,6 +1916,7 @@ mod tests {
         let ctx = CircuitContext {
             failure_classifier: None,
             bulkhead: None,
+            recovery_guard: None,
             name: "test_circuit".to_string(),
             config,
             storage: storage.clone(),
@@ -741,6 +1953,7 @@ mod tests {
         let ctx = CircuitContext {
             failure_classifier: None,
             bulkhead: None,
+            recovery_guard: None,
             name: "test_circuit".to_string(),
             config,
             storage: storage.clone(),
@@ -790,6 +2003,7 @@ mod tests {
         let ctx = CircuitContext {
             failure_classifier: None,
             bulkhead: None,
+            recovery_guard: None,
             name: "test_circuit".to_string(),
             config,
             storage: storage.clone(),
@@ -830,6 +2044,7 @@ mod tests {
         let ctx = CircuitContext {
             failure_classifier: None,
             bulkhead: None,
+            recovery_guard: None,
             name: "test_circuit".to_string(),
             config,
             storage: storage.clone(),
@@ -868,6 +2083,7 @@ mod tests {
         let ctx = CircuitContext {
             failure_classifier: None,
             bulkhead: None,
+            recovery_guard: None,
             name: "test_circuit".to_string(),
             config,
             storage: storage.clone(),
@@ -885,39 +2101,568 @@ mod tests {
                 data.opened_at = storage.monotonic_time();
             }
 
-            // With 10% jitter, timeout should be 900-1000ms
-            // Try at 950ms - should sometimes succeed (jitter applied)
-            std::thread::sleep(std::time::Duration::from_millis(950));
+            // With 10% jitter, timeout should be 900-1000ms
+            // Try at 950ms - should sometimes succeed (jitter applied)
+            std::thread::sleep(std::time::Duration::from_millis(950));
+
+            if circuit.handle(CircuitEvent::AttemptReset).is_ok() {
+                found_early_reset = true;
+                break;
+            }
+
+            storage.clear("test_circuit");
+        }
+
+        assert!(
+            found_early_reset,
+            "Jitter should occasionally allow reset before full timeout"
+        );
+    }
+
+    #[test]
+    fn test_builder_with_jitter() {
+        let mut circuit = CircuitBreaker::builder("test")
+            .failure_threshold(2)
+            .half_open_timeout_secs(1.0)
+            .jitter_factor(0.5) // 50% jitter
+            .build();
+
+        // Trigger failures
+        let _ = circuit.call(|| Err::<(), _>("error 1"));
+        let _ = circuit.call(|| Err::<(), _>("error 2"));
+        assert!(circuit.is_open());
+
+        // Verify jitter_factor was set
+        assert_eq!(circuit.context.config.jitter_factor, 0.5);
+    }
+
+    #[test]
+    fn test_half_open_backoff_grows_until_close() {
+        let clock = ManualClock::default();
+        let storage = Arc::new(crate::MemoryStorage::with_clock(Box::new(clock.clone())));
+        let mut circuit = CircuitBreaker::builder("test")
+            .storage(storage)
+            .failure_threshold(1)
+            .half_open_timeout_secs(10.0)
+            .half_open_backoff(2.0, 30.0)
+            .success_threshold(1)
+            .build();
+        let attempt = |circuit: &mut CircuitBreaker| {
+            circuit
+                .call((
+                    || Ok::<_, &str>(0),
+                    CallOptions::new().with_fallback(|ctx| Ok(ctx.attempt)),
+                ))
+                .unwrap()
+        };
+
+        let _ = circuit.call(|| Err::<(), _>("down"));
+        assert_eq!(attempt(&mut circuit), 1);
+
+        // Failed probe at 10s: wait 20s
+        clock.set(10.0);
+        let _ = circuit.call(|| Err::<(), _>("still down"));
+        clock.set(29.0);
+        assert_eq!(attempt(&mut circuit), 2);
+
+        // Failed probe at 30s: 40s capped to 30s
+        clock.set(30.0);
+        let _ = circuit.call(|| Err::<(), _>("still down"));
+        clock.set(59.0);
+        assert_eq!(attempt(&mut circuit), 3);
+
+        clock.set(60.0);
+        assert!(circuit.call(|| Ok::<_, &str>("recovered")).is_ok());
+        assert!(circuit.is_closed());
+
+        // Closing resets the backoff
+        let _ = circuit.call(|| Err::<(), _>("down again"));
+        clock.set(69.0);
+        assert_eq!(attempt(&mut circuit), 1);
+        clock.set(70.0);
+        assert_eq!(
+            attempt(&mut circuit),
+            0,
+            "probe should run after the base timeout"
+        );
+    }
+
+    #[test]
+    fn test_backoff_survives_restart() {
+        let clock = ManualClock::default();
+        let storage = Arc::new(crate::MemoryStorage::with_clock(Box::new(clock.clone())));
+        let build = |storage: &Arc<crate::MemoryStorage>| {
+            CircuitBreaker::builder("test")
+                .storage(storage.clone())
+                .failure_threshold(1)
+                .half_open_timeout_secs(10.0)
+                .half_open_backoff(2.0, 60.0)
+                .jitter_factor(0.5)
+                .build()
+        };
+
+        // Trip, then fail two probes: the third Open period backs off to 40s
+        let mut circuit = build(&storage);
+        let _ = circuit.call(|| Err::<(), _>("down"));
+        for _ in 0..2 {
+            clock.advance(circuit.retry_after().unwrap() + 0.001);
+            let _ = circuit.call(|| Err::<(), _>("still down"));
+        }
+        let wait = circuit.retry_after().unwrap();
+        assert!((20.0..=40.0).contains(&wait), "wait {wait} not backed off");
+        drop(circuit);
+
+        // A restarted worker resumes the same attempt and jittered deadline
+        clock.advance(1.0);
+        let mut restarted = build(&storage);
+        assert!(restarted.is_open());
+        assert!((restarted.retry_after().unwrap() - (wait - 1.0)).abs() < 1e-9);
+        let attempt = restarted
+            .call((
+                || Ok::<_, &str>(0),
+                CallOptions::new().with_fallback(|ctx| Ok(ctx.attempt)),
+            ))
+            .unwrap();
+        assert_eq!(attempt, 3);
+
+        // Its failed probe keeps backing off from there
+        clock.advance(wait - 1.0 + 0.001);
+        let _ = restarted.call(|| Err::<(), _>("still down"));
+        let wait = restarted.retry_after().unwrap();
+        assert!((30.0..=60.0).contains(&wait), "wait {wait} not backed off");
+    }
+
+    #[test]
+    fn test_jitter_is_drawn_once_per_open_period() {
+        let clock = ManualClock::default();
+        let storage = Arc::new(crate::MemoryStorage::with_clock(Box::new(clock.clone())));
+        let mut circuit = CircuitBreaker::builder("test")
+            .storage(storage)
+            .failure_threshold(1)
+            .half_open_timeout_secs(10.0)
+            .jitter_factor(0.5)
+            .build();
+        assert_eq!(circuit.retry_after(), None);
+
+        let _ = circuit.call(|| Err::<(), _>("down"));
+        let wait = circuit.retry_after().expect("circuit should be open");
+        assert!(
+            (5.0..=10.0).contains(&wait),
+            "jittered wait {wait} out of range"
+        );
+
+        // Re-checking the Open state must not re-roll the deadline
+        clock.set(wait - 0.01);
+        for _ in 0..100 {
+            let result = circuit.call(|| Ok::<_, &str>("early"));
+            assert!(matches!(result, Err(CircuitError::Open { .. })));
+        }
+        assert!((circuit.retry_after().unwrap() - 0.01).abs() < 1e-9);
+
+        clock.set(wait);
+        assert_eq!(circuit.retry_after(), Some(0.0));
+        assert!(circuit.call(|| Ok::<_, &str>("probe")).is_ok());
+        assert_eq!(circuit.retry_after(), None);
+    }
+
+    #[test]
+    fn test_open_error_reports_retry_after() {
+        let clock = ManualClock::default();
+        let storage = Arc::new(crate::MemoryStorage::with_clock(Box::new(clock.clone())));
+        let mut circuit = CircuitBreaker::builder("test")
+            .storage(storage)
+            .failure_threshold(1)
+            .half_open_timeout_secs(30.0)
+            .build();
+        let _ = circuit.call(|| Err::<(), _>("down"));
+
+        clock.set(10.0);
+        let error = circuit.call(|| Ok::<_, &str>("rejected")).unwrap_err();
+        assert!(matches!(
+            error,
+            CircuitError::Open {
+                retry_after_secs: 20.0,
+                timeout_secs: 30.0,
+                ..
+            }
+        ));
+        assert_eq!(error.retry_after_secs(), Some(20.0));
+        assert_eq!(
+            error.to_string(),
+            "Circuit 'test' is open (retry after 20.000s)"
+        );
+
+        let (retry_after, timeout) = circuit
+            .call((
+                || Ok::<_, &str>((0.0, 0.0)),
+                CallOptions::new()
+                    .with_fallback(|ctx| Ok((ctx.retry_after_secs, ctx.timeout_secs))),
+            ))
+            .unwrap();
+        assert_eq!((retry_after, timeout), (20.0, 30.0));
+    }
+
+    #[test]
+    fn test_force_open_rejects_until_released() {
+        let clock = ManualClock::default();
+        let storage = Arc::new(crate::MemoryStorage::with_clock(Box::new(clock.clone())));
+        let opened = Arc::new(core::sync::atomic::AtomicUsize::new(0));
+        let opened_clone = opened.clone();
+        let mut circuit = CircuitBreaker::builder("test")
+            .storage(storage)
+            .half_open_timeout_secs(10.0)
+            .on_open(move |_| {
+                opened_clone.fetch_add(1, core::sync::atomic::Ordering::SeqCst);
+            })
+            .build();
+
+        circuit.force_open();
+        assert!(circuit.is_open());
+        assert_eq!(circuit.state_name(), "ForcedOpen");
+        assert_eq!(circuit.override_mode(), Some(CircuitOverride::ForcedOpen));
+        assert_eq!(opened.load(core::sync::atomic::Ordering::SeqCst), 1);
+
+        // The timeout never moves a forced-open circuit to HalfOpen
+        clock.set(60.0);
+        let error = circuit.call(|| Ok::<_, &str>("blocked")).unwrap_err();
+        assert_eq!(error.retry_after_secs(), Some(f64::INFINITY));
+        let state = circuit
+            .call((
+                || Ok::<_, &str>("blocked"),
+                CallOptions::new().with_fallback(|ctx| Ok(ctx.state)),
+            ))
+            .unwrap();
+        assert_eq!(state, "ForcedOpen");
+
+        // Once released, the elapsed timeout lets a probe through
+        circuit.release_override();
+        assert_eq!(circuit.state_name(), "HalfOpen");
+        assert!(circuit.call(|| Ok::<_, &str>("probe")).is_ok());
+    }
+
+    #[test]
+    fn test_release_override_persists_the_released_state() {
+        let clock = ManualClock::default();
+        let storage = Arc::new(crate::MemoryStorage::with_clock(Box::new(clock.clone())));
+        let half_opened = Arc::new(core::sync::atomic::AtomicBool::new(false));
+        let half_opened_clone = half_opened.clone();
+        let mut circuit = CircuitBreaker::builder("test")
+            .storage(storage.clone())
+            .half_open_timeout_secs(10.0)
+            .on_half_open(move |_| {
+                half_opened_clone.store(true, core::sync::atomic::Ordering::SeqCst)
+            })
+            .build();
+
+        // Released before the timeout: a plain Open period
+        circuit.force_open();
+        storage.set_status("test", CircuitStatus::new(CircuitState::Closed));
+        clock.set(5.0);
+        circuit.release_override();
+        assert_eq!(circuit.state_name(), "Open");
+        let status = storage.get_status("test").unwrap();
+        assert_eq!(status.state, CircuitState::Open);
+        assert_eq!(status.opened_at, Some(0.0));
+        assert!(!half_opened.load(core::sync::atomic::Ordering::SeqCst));
+
+        // Released after it: HalfOpen, announced and persisted
+        circuit.force_open();
+        clock.set(60.0);
+        circuit.release_override();
+        assert!(half_opened.load(core::sync::atomic::Ordering::SeqCst));
+        assert_eq!(
+            storage.get_status("test").map(|status| status.state),
+            Some(CircuitState::HalfOpen)
+        );
+
+        // A restarted instance resumes the released state, not the override
+        let restarted = CircuitBreaker::builder("test").storage(storage).build();
+        assert_eq!(restarted.state_name(), "HalfOpen");
+    }
+
+    #[test]
+    fn test_force_close_ignores_thresholds() {
+        let closed = Arc::new(core::sync::atomic::AtomicBool::new(false));
+        let closed_clone = closed.clone();
+        let mut circuit = CircuitBreaker::builder("test")
+            .failure_threshold(2)
+            .on_close(move |_| closed_clone.store(true, core::sync::atomic::Ordering::SeqCst))
+            .build();
+
+        let _ = circuit.call(|| Err::<(), _>("error 1"));
+        let _ = circuit.call(|| Err::<(), _>("error 2"));
+        assert!(circuit.is_open());
+
+        circuit.force_close();
+        assert!(closed.load(core::sync::atomic::Ordering::SeqCst));
+        assert_eq!(circuit.state_name(), "ForcedClosed");
+
+        for _ in 0..5 {
+            let _ = circuit.call(|| Err::<(), _>("error"));
+        }
+        assert!(circuit.is_closed());
+        assert_eq!(circuit.stats().failure_count, 7);
+
+        // Thresholds apply again once released
+        circuit.release_override();
+        let _ = circuit.call(|| Err::<(), _>("error"));
+        assert!(circuit.is_open());
+    }
+
+    #[test]
+    fn test_disabled_passes_calls_through_unrecorded() {
+        let mut circuit = CircuitBreaker::builder("test")
+            .failure_threshold(1)
+            .max_concurrency(1)
+            .build();
+        let _ = circuit.call(|| Err::<(), _>("trip"));
+        assert!(circuit.is_open());
+
+        circuit.disable();
+        assert_eq!(circuit.state_name(), "Disabled");
+        assert_eq!(
+            circuit.call(|| Ok::<_, &str>("through")).unwrap(),
+            "through"
+        );
+        assert!(matches!(
+            circuit.call(|| Err::<(), _>("error")),
+            Err(CircuitError::Execution("error"))
+        ));
+        assert_eq!(circuit.stats().failure_count, 1);
+        assert_eq!(circuit.stats().success_count, 0);
+        assert!(circuit.is_closed());
+
+        circuit.reset();
+        assert_eq!(circuit.override_mode(), None);
+        assert_eq!(circuit.state_name(), "Closed");
+    }
+
+    #[test]
+    fn test_cascade_holds_dependent_open_until_parent_closes() {
+        let clock = ManualClock::default();
+        let cascades = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let cascades_clone = cascades.clone();
+        let mut reports = CircuitBreaker::builder("reports")
+            .on_cascade(move |circuit, source| {
+                cascades_clone
+                    .lock()
+                    .unwrap()
+                    .push((circuit.to_string(), source.to_string()));
+            })
+            .build();
+        let mut database = CircuitBreaker::builder("database")
+            .storage(Arc::new(crate::MemoryStorage::with_clock(Box::new(
+                clock.clone(),
+            ))))
+            .failure_threshold(1)
+            .half_open_timeout_secs(10.0)
+            .cascades_to(reports.cascade_target())
+            .build();
+
+        let _ = database.call(|| Err::<(), _>("down"));
+        assert!(database.is_open());
+        assert!(reports.is_open());
+        assert_eq!(reports.state_name(), "CascadeOpen");
+        assert_eq!(reports.cascade_source().as_deref(), Some("database"));
+        assert_eq!(
+            *cascades.lock().unwrap(),
+            [("reports".to_string(), "database".to_string())]
+        );
+
+        let error = reports.call(|| Ok::<_, &str>("blocked")).unwrap_err();
+        assert!(matches!(error, CircuitError::Open { ref circuit, .. } if circuit == "reports"));
+        assert_eq!(error.retry_after_secs(), Some(f64::INFINITY));
+        let state = reports
+            .call((
+                || Ok::<_, &str>("blocked"),
+                CallOptions::new().with_fallback(|ctx| Ok(ctx.state)),
+            ))
+            .unwrap();
+        assert_eq!(state, "CascadeOpen");
+
+        // A probing parent still holds the dependent open
+        clock.set(20.0);
+        assert!(database.call(|| Ok::<_, &str>("probe")).is_ok());
+        assert_eq!(database.state_name(), "HalfOpen");
+        assert!(reports.call(|| Ok::<_, &str>("blocked")).is_err());
+
+        let _ = database.call(|| Ok::<_, &str>("probe"));
+        assert!(database.is_closed());
+        assert!(reports.is_closed());
+        assert_eq!(reports.cascade_source(), None);
+        assert!(reports.call(|| Ok::<_, &str>("report")).is_ok());
+        assert_eq!(cascades.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_cascade_fires_the_dependents_open_callback() {
+        let opened = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let opened_clone = opened.clone();
+        let reports = CircuitBreaker::builder("reports")
+            .on_open(move |circuit| opened_clone.lock().unwrap().push(circuit.to_string()))
+            .build();
+        let mut database = CircuitBreaker::builder("database")
+            .failure_threshold(1)
+            .cascades_to(reports.cascade_target())
+            .build();
+        let mut cache = CircuitBreaker::builder("cache")
+            .failure_threshold(1)
+            .cascades_to(reports.cascade_target())
+            .build();
+
+        let _ = database.call(|| Err::<(), _>("down"));
+        let _ = cache.call(|| Err::<(), _>("down"));
+        assert_eq!(*opened.lock().unwrap(), ["reports"]);
+    }
+
+    #[test]
+    fn test_cascade_release_fires_the_dependents_close_callback() {
+        let closed = Arc::new(core::sync::atomic::AtomicUsize::new(0));
+        let closed_clone = closed.clone();
+        let reports = CircuitBreaker::builder("reports")
+            .on_close(move |_| {
+                closed_clone.fetch_add(1, Ordering::SeqCst);
+            })
+            .build();
+        let mut database = CircuitBreaker::builder("database")
+            .failure_threshold(1)
+            .cascades_to(reports.cascade_target())
+            .build();
+        let mut cache = CircuitBreaker::builder("cache")
+            .failure_threshold(1)
+            .cascades_to(reports.cascade_target())
+            .build();
+
+        let _ = database.call(|| Err::<(), _>("down"));
+        let _ = cache.call(|| Err::<(), _>("down"));
+        database.force_close();
+        assert_eq!(
+            closed.load(Ordering::SeqCst),
+            0,
+            "cache still holds it open"
+        );
+        cache.force_close();
+        assert_eq!(closed.load(Ordering::SeqCst), 1);
+
+        // Clearing a released latch fires nothing
+        reports.clear_cascade();
+        assert_eq!(closed.load(Ordering::SeqCst), 1);
+
+        database.release_override();
+        let _ = database.call(|| Err::<(), _>("down again"));
+        reports.clear_cascade();
+        assert_eq!(closed.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_cascade_released_when_parent_is_dropped_or_forced_closed() {
+        let reports = CircuitBreaker::builder("reports").build();
+        let mut database = CircuitBreaker::builder("database")
+            .failure_threshold(1)
+            .cascades_to(reports.cascade_target())
+            .build();
+        let _ = database.call(|| Err::<(), _>("down"));
+        assert!(reports.is_open());
+
+        database.force_close();
+        assert!(reports.is_closed());
+        database.release_override();
+
+        let _ = database.call(|| Err::<(), _>("down again"));
+        assert!(reports.is_open());
+        drop(database);
+        assert!(reports.is_closed());
+        assert_eq!(reports.cascade_source(), None);
+    }
+
+    #[test]
+    fn test_clear_cascade_unlatches_the_dependent() {
+        let mut reports = CircuitBreaker::builder("reports").build();
+        let mut database = CircuitBreaker::builder("database")
+            .failure_threshold(1)
+            .cascades_to(reports.cascade_target())
+            .build();
+        let _ = database.call(|| Err::<(), _>("down"));
+        assert_eq!(reports.state_name(), "CascadeOpen");
+
+        reports.clear_cascade();
+        assert_eq!(reports.state_name(), "Closed");
+        assert!(reports.call(|| Ok::<_, &str>("report")).is_ok());
+    }
+
+    #[test]
+    fn test_cascade_waits_for_every_parent() {
+        let mut reports = CircuitBreaker::builder("reports").build();
+        let mut database = CircuitBreaker::builder("database")
+            .cascades_to(reports.cascade_target())
+            .build();
+        let mut search = CircuitBreaker::builder("search")
+            .cascades_to(reports.cascade_target())
+            .build();
 
-            if circuit.handle(CircuitEvent::AttemptReset).is_ok() {
-                found_early_reset = true;
-                break;
-            }
+        database.force_open();
+        search.force_open();
+        database.force_close();
+        assert_eq!(reports.cascade_source().as_deref(), Some("search"));
+        assert!(reports.is_open());
 
-            storage.clear("test_circuit");
-        }
+        search.reset();
+        assert!(reports.is_closed());
 
-        assert!(
-            found_early_reset,
-            "Jitter should occasionally allow reset before full timeout"
-        );
+        // Operator overrides take precedence over a cascade
+        database.force_open();
+        reports.force_close();
+        assert!(reports.call(|| Ok::<_, &str>("report")).is_ok());
+        reports.release_override();
+        assert_eq!(reports.state_name(), "CascadeOpen");
     }
 
     #[test]
-    fn test_builder_with_jitter() {
-        let mut circuit = CircuitBreaker::builder("test")
-            .failure_threshold(2)
-            .half_open_timeout_secs(1.0)
-            .jitter_factor(0.5) // 50% jitter
+    fn test_recovery_guard_holds_transitions() {
+        let clock = ManualClock::default();
+        let healthy = Arc::new(core::sync::atomic::AtomicBool::new(false));
+        let attempts = Arc::new(core::sync::atomic::AtomicU32::new(0));
+        let (signal, seen) = (healthy.clone(), attempts.clone());
+        let mut circuit = CircuitBreaker::builder("api")
+            .storage(Arc::new(crate::MemoryStorage::with_clock(Box::new(
+                clock.clone(),
+            ))))
+            .failure_threshold(1)
+            .half_open_timeout_secs(10.0)
+            .success_threshold(2)
+            .recovery_guard(Arc::new(crate::PredicateRecoveryGuard::new(move |ctx| {
+                assert_eq!(ctx.circuit_name, "api");
+                seen.store(ctx.attempt, core::sync::atomic::Ordering::SeqCst);
+                signal.load(core::sync::atomic::Ordering::SeqCst)
+            })))
             .build();
 
-        // Trigger failures
-        let _ = circuit.call(|| Err::<(), _>("error 1"));
-        let _ = circuit.call(|| Err::<(), _>("error 2"));
+        let _ = circuit.call(|| Err::<(), _>("down"));
         assert!(circuit.is_open());
 
-        // Verify jitter_factor was set
-        assert_eq!(circuit.context.config.jitter_factor, 0.5);
+        // The timeout has elapsed, but the guard keeps the circuit Open
+        clock.set(20.0);
+        assert!(circuit.call(|| Ok::<_, &str>("blocked")).is_err());
+        assert_eq!(circuit.state_name(), "Open");
+        assert_eq!(attempts.load(core::sync::atomic::Ordering::SeqCst), 1);
+
+        healthy.store(true, core::sync::atomic::Ordering::SeqCst);
+        assert!(circuit.call(|| Ok::<_, &str>("probe")).is_ok());
+        assert_eq!(circuit.state_name(), "HalfOpen");
+
+        // Enough successful probes, but closing is held back too
+        healthy.store(false, core::sync::atomic::Ordering::SeqCst);
+        assert!(circuit.call(|| Ok::<_, &str>("probe")).is_ok());
+        assert_eq!(circuit.state_name(), "HalfOpen");
+        assert!(matches!(
+            circuit.call(|| Ok::<_, &str>("no probes left")),
+            Err(CircuitError::HalfOpenLimitReached { .. })
+        ));
+
+        // The next call after the guard relents closes the circuit
+        healthy.store(true, core::sync::atomic::Ordering::SeqCst);
+        assert!(circuit.call(|| Ok::<_, &str>("closed")).is_ok());
+        assert!(circuit.is_closed());
     }
 
     #[test]
@@ -964,6 +2709,68 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_fallback_chain_runs_in_order() {
+        let mut circuit = CircuitBreaker::builder("test").build();
+        circuit.force_open();
+
+        let result = circuit.call((
+            || Ok::<_, &str>("should not execute"),
+            CallOptions::new()
+                .with_fallback(|_ctx| Err("cache miss"))
+                .with_fallback(|ctx| {
+                    assert_eq!(ctx.reason, FallbackReason::Open);
+                    Ok("stale copy")
+                })
+                .with_fallback(|_ctx| panic!("not reached")),
+        ));
+        assert_eq!(result.unwrap(), "stale copy");
+
+        let result = circuit.call((
+            || Ok::<(), _>(()),
+            CallOptions::new()
+                .with_fallback(|_ctx| Err("first"))
+                .with_fallback(|_ctx| Err("last")),
+        ));
+        assert!(matches!(result, Err(CircuitError::Execution("last"))));
+    }
+
+    #[test]
+    fn test_fallback_on_other_errors() {
+        let mut circuit = CircuitBreaker::builder("test").failure_threshold(5).build();
+
+        // Only Open triggers the fallbacks by default
+        let result = circuit.call((
+            || Err::<&str, _>("boom"),
+            CallOptions::new().with_fallback(|_ctx| Ok("fallback")),
+        ));
+        assert!(matches!(result, Err(CircuitError::Execution("boom"))));
+
+        let result = circuit.call((
+            || Err::<&str, _>("boom"),
+            CallOptions::new()
+                .with_fallback(|ctx| {
+                    assert_eq!(ctx.reason, FallbackReason::Execution);
+                    assert_eq!(ctx.state, "Closed");
+                    Ok("fallback")
+                })
+                .fallback_on(FallbackReason::Execution),
+        ));
+        assert_eq!(result.unwrap(), "fallback");
+        // The failure still counts against the circuit
+        assert_eq!(circuit.stats().failure_count, 2);
+
+        let mut circuit = CircuitBreaker::builder("test").max_concurrency(1).build();
+        let _held = circuit.context.bulkhead.as_ref().unwrap().try_acquire();
+        let result = circuit.call((
+            || Ok::<_, &str>(FallbackReason::Execution),
+            CallOptions::new()
+                .with_fallback(|ctx| Ok(ctx.reason))
+                .fallback_on(FallbackReason::BulkheadFull),
+        ));
+        assert_eq!(result.unwrap(), FallbackReason::BulkheadFull);
+    }
+
     #[test]
     fn test_rate_based_threshold() {
         let mut circuit = CircuitBreaker::builder("test")
@@ -1031,6 +2838,88 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_slow_call_rate_trips_on_slow_successes() {
+        let mut circuit = CircuitBreaker::builder("test")
+            .disable_failure_threshold()
+            .slow_call_duration_secs(1.0)
+            .slow_call_rate(0.5)
+            .minimum_calls(4)
+            .build();
+
+        circuit.record_success_and_maybe_close(0.1);
+        circuit.record_success_and_maybe_close(2.0);
+        circuit.record_success_and_maybe_close(0.1);
+        assert!(circuit.is_closed(), "Circuit opened before minimum calls");
+
+        circuit.record_success_and_maybe_close(5.0);
+        assert!(circuit.is_open(), "Slow successes did not trip the circuit");
+
+        let stats = circuit.stats();
+        assert_eq!(stats.state, CircuitState::Open);
+        assert_eq!(stats.success_count, 4);
+        assert_eq!(stats.failure_count, 0);
+        assert_eq!(stats.slow_call_count, 2);
+        assert!(stats.opened_at.is_some());
+    }
+
+    #[test]
+    fn test_slow_calls_counted_without_rate_threshold() {
+        let mut circuit = CircuitBreaker::builder("test")
+            .slow_call_duration_secs(1.0)
+            .minimum_calls(1)
+            .build();
+
+        for _ in 0..3 {
+            circuit.record_success_and_maybe_close(2.0);
+        }
+        circuit.record_failure_and_maybe_trip(3.0);
+
+        let stats = circuit.stats();
+        assert!(circuit.is_closed());
+        assert_eq!(stats.slow_call_count, 4);
+        assert_eq!(stats.opened_at, None);
+    }
+
+    #[test]
+    fn test_count_window_ignores_event_age() {
+        // A time window this short would never hold enough calls
+        let mut circuit = CircuitBreaker::builder("test")
+            .disable_failure_threshold()
+            .failure_window_secs(0.000_001)
+            .count_window(4)
+            .failure_rate(0.5)
+            .minimum_calls(4)
+            .build();
+
+        circuit.record_success_and_maybe_close(0.1);
+        circuit.record_failure_and_maybe_trip(0.1);
+        circuit.record_success_and_maybe_close(0.1);
+        assert!(circuit.is_closed(), "Circuit opened before minimum calls");
+
+        circuit.record_failure_and_maybe_trip(0.1);
+        assert!(circuit.is_open(), "Count window did not trip the circuit");
+        assert_eq!(circuit.stats().failure_count, 2);
+    }
+
+    #[test]
+    fn test_count_window_forgets_older_calls() {
+        let mut circuit = CircuitBreaker::builder("test")
+            .failure_threshold(2)
+            .count_window(3)
+            .build();
+
+        circuit.record_failure_and_maybe_trip(0.1);
+        circuit.record_success_and_maybe_close(0.1);
+        circuit.record_success_and_maybe_close(0.1);
+        circuit.record_failure_and_maybe_trip(0.1);
+
+        // The first failure slid out of the last 3 calls
+        assert!(circuit.is_closed());
+        let stats = circuit.stats();
+        assert_eq!((stats.success_count, stats.failure_count), (2, 1));
+    }
+
     #[test]
     fn test_failure_classifier_filters_errors() {
         use crate::classifier::PredicateClassifier;
@@ -1392,6 +3281,113 @@ mod tests {
         assert!(circuit.is_closed(), "circuit should recover and close");
     }
 
+    #[test]
+    fn test_call_with_timeout_records_failure_and_holds_permit() {
+        let mut circuit = CircuitBreaker::builder("test")
+            .failure_threshold(2)
+            .max_concurrency(1)
+            .call_timeout_secs(0.01)
+            .build();
+        let (release, hung) = std::sync::mpsc::channel::<()>();
+
+        let result = circuit.call_with_timeout(move || {
+            let _ = hung.recv();
+            Ok::<_, String>("late")
+        });
+        assert!(matches!(
+            result,
+            Err(CircuitError::Timeout { ref circuit, timeout_secs }) if circuit == "test" && timeout_secs == 0.01
+        ));
+        assert_eq!(circuit.stats().failure_count, 1);
+
+        // The abandoned worker still holds the only permit
+        let result = circuit.call_with_timeout(|| Ok::<_, String>("blocked"));
+        assert!(matches!(result, Err(CircuitError::BulkheadFull { .. })));
+
+        release.send(()).unwrap();
+        let recovered = (0..1000).find_map(|_| {
+            std::thread::sleep(std::time::Duration::from_millis(1));
+            circuit.call_with_timeout(|| Ok::<_, String>("ok")).ok()
+        });
+        assert_eq!(recovered, Some("ok"));
+    }
+
+    #[test]
+    fn test_call_with_timeout_probe_panic_releases_slot() {
+        let mut circuit = CircuitBreaker::builder("test")
+            .failure_threshold(1)
+            .half_open_timeout_secs(0.0)
+            .success_threshold(1)
+            .call_timeout_secs(5.0)
+            .build();
+        let _ = circuit.call(|| Err::<(), _>("error"));
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            circuit.call_with_timeout(|| -> Result<(), String> { panic!("boom") })
+        }));
+        assert!(result.is_err(), "the worker's panic should resume");
+        assert_eq!(circuit.state_name(), "HalfOpen");
+
+        let result = circuit.call_with_timeout(|| Ok::<_, String>("probe"));
+        assert_eq!(result.unwrap(), "probe");
+        assert!(circuit.is_closed());
+    }
+
+    #[test]
+    fn test_call_with_timeout_refuses_calls_past_abandoned_limit() {
+        let mut circuit = CircuitBreaker::builder("test")
+            .failure_threshold(10)
+            .call_timeout_secs(0.01)
+            .max_abandoned_calls(1)
+            .build();
+        let (release, hung) = std::sync::mpsc::channel::<()>();
+
+        let result = circuit.call_with_timeout(move || {
+            let _ = hung.recv();
+            Ok::<_, String>("late")
+        });
+        assert!(matches!(result, Err(CircuitError::Timeout { .. })));
+
+        // No bulkhead, but the hung worker is still running
+        let result = circuit.call_with_timeout(|| Ok::<_, String>("refused"));
+        assert!(matches!(
+            result,
+            Err(CircuitError::TimeoutWorkersExhausted { limit: 1, .. })
+        ));
+        assert_eq!(circuit.stats().failure_count, 1);
+
+        release.send(()).unwrap();
+        let recovered = (0..1000).find_map(|_| {
+            std::thread::sleep(std::time::Duration::from_millis(1));
+            circuit.call_with_timeout(|| Ok::<_, String>("ok")).ok()
+        });
+        assert_eq!(recovered, Some("ok"));
+    }
+
+    #[test]
+    fn test_call_with_timeout_and_options_falls_back_on_timeout() {
+        let mut circuit = CircuitBreaker::builder("test")
+            .call_timeout_secs(0.01)
+            .build();
+        let (release, hung) = std::sync::mpsc::channel::<()>();
+
+        let result = circuit.call_with_timeout_and_options(
+            move || {
+                let _ = hung.recv();
+                Ok::<_, String>("late")
+            },
+            CallOptions::new()
+                .with_fallback(|ctx| {
+                    assert_eq!(ctx.reason, FallbackReason::Timeout);
+                    Ok("fallback")
+                })
+                .fallback_on(FallbackReason::Timeout),
+        );
+        assert_eq!(result.unwrap(), "fallback");
+        assert_eq!(circuit.stats().failure_count, 1);
+        release.send(()).unwrap();
+    }
+
     #[test]
     fn test_open_fallback_releases_bulkhead_permit() {
         use std::sync::Arc;
@@ -1420,6 +3416,103 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_restores_open_state_from_storage() {
+        let storage = Arc::new(crate::MemoryStorage::new());
+
+        let mut first = CircuitBreaker::builder("test")
+            .storage(storage.clone())
+            .failure_threshold(1)
+            .build();
+        let _ = first.call(|| Err::<(), _>("error"));
+        assert!(first.is_open());
+        let opened_at = first.machine.open_data().expect("Open data").opened_at;
+
+        // A fresh instance (e.g. a restarted worker) resumes the open circuit
+        let mut second = CircuitBreaker::builder("test")
+            .storage(storage)
+            .failure_threshold(1)
+            .build();
+        assert!(second.is_open(), "restored circuit should stay open");
+        assert_eq!(
+            second.machine.open_data().expect("Open data").opened_at,
+            opened_at
+        );
+
+        let result = second.call(|| Ok::<_, &str>("should not execute"));
+        assert!(matches!(result, Err(CircuitError::Open { .. })));
+    }
+
+    #[test]
+    fn test_restored_open_circuit_recovers_after_timeout() {
+        let storage = Arc::new(crate::MemoryStorage::new());
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        // Opened well before this instance existed; the timeout has already passed
+        storage.set_status("test", CircuitStatus::open(0.0));
+
+        let mut circuit = CircuitBreaker::builder("test")
+            .storage(storage.clone())
+            .half_open_timeout_secs(0.001)
+            .success_threshold(1)
+            .build();
+        assert!(circuit.is_open());
+
+        let result = circuit.call(|| Ok::<_, &str>("probe"));
+        assert_eq!(result.unwrap(), "probe");
+        assert!(circuit.is_closed());
+        assert_eq!(
+            storage.get_status("test").map(|s| s.state),
+            Some(CircuitState::Closed),
+            "closing should be persisted"
+        );
+    }
+
+    #[test]
+    fn test_restore_clamps_opened_at_from_the_future() {
+        let storage = Arc::new(crate::MemoryStorage::new());
+        storage.set_status("test", CircuitStatus::open(1.0e9));
+
+        let circuit = CircuitBreaker::builder("test")
+            .storage(storage.clone())
+            .build();
+
+        let opened_at = circuit.machine.open_data().expect("Open data").opened_at;
+        assert!(opened_at <= storage.monotonic_time());
+    }
+
+    #[test]
+    fn test_transitions_are_persisted() {
+        let storage = Arc::new(crate::MemoryStorage::new());
+        let mut circuit = CircuitBreaker::builder("test")
+            .storage(storage.clone())
+            .failure_threshold(1)
+            .half_open_timeout_secs(0.001)
+            .success_threshold(2)
+            .build();
+
+        assert_eq!(storage.get_status("test"), None);
+
+        let _ = circuit.call(|| Err::<(), _>("error"));
+        let status = storage.get_status("test").expect("status after trip");
+        assert_eq!(status.state, CircuitState::Open);
+        assert!(status.opened_at.is_some());
+
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        storage.clear("test");
+        storage.set_status("test", status);
+        let _ = circuit.call(|| Ok::<_, &str>("probe"));
+        assert_eq!(
+            storage.get_status("test").map(|s| s.state),
+            Some(CircuitState::HalfOpen)
+        );
+
+        circuit.reset();
+        assert_eq!(
+            storage.get_status("test").map(|s| s.state),
+            Some(CircuitState::Closed)
+        );
+    }
+
     #[test]
     fn test_jitter_distribution_within_bounds() {
         // Test that jitter produces values within expected bounds
@@ -1438,6 +3531,7 @@ mod tests {
         let ctx = CircuitContext {
             failure_classifier: None,
             bulkhead: None,
+            recovery_guard: None,
             name: "jitter_test".to_string(),
             config,
             storage: storage.clone(),
@@ -1502,6 +3596,7 @@ mod tests {
         let _ctx = CircuitContext {
             failure_classifier: None,
             bulkhead: None,
+            recovery_guard: None,
             name: "jitter_variance".to_string(),
             config,
             storage,
@@ -1553,4 +3648,181 @@ mod tests {
         );
         assert!(values.contains(&1000), "Timeout should be exactly 1000ms");
     }
+
+    /// Memory storage whose fallible methods fail while `down` is set
+    #[derive(Debug, Default)]
+    struct OutageStorage {
+        inner: crate::MemoryStorage,
+        down: core::sync::atomic::AtomicBool,
+    }
+
+    impl OutageStorage {
+        fn set_down(&self, down: bool) {
+            self.down.store(down, core::sync::atomic::Ordering::SeqCst);
+        }
+
+        fn check(&self) -> Result<(), StorageError> {
+            if self.down.load(core::sync::atomic::Ordering::SeqCst) {
+                Err(StorageError::Unavailable("outage".to_string()))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    impl StorageBackend for OutageStorage {
+        fn record_success(&self, circuit_name: &str, duration: f64) {
+            let _ = self.try_record_success(circuit_name, duration);
+        }
+
+        fn record_failure(&self, circuit_name: &str, duration: f64) {
+            let _ = self.try_record_failure(circuit_name, duration);
+        }
+
+        fn success_count(&self, circuit_name: &str, window_seconds: f64) -> usize {
+            self.try_success_count(circuit_name, window_seconds)
+                .unwrap_or(0)
+        }
+
+        fn failure_count(&self, circuit_name: &str, window_seconds: f64) -> usize {
+            self.try_failure_count(circuit_name, window_seconds)
+                .unwrap_or(0)
+        }
+
+        fn clear(&self, circuit_name: &str) {
+            self.inner.clear(circuit_name);
+        }
+
+        fn clear_all(&self) {
+            self.inner.clear_all();
+        }
+
+        fn event_log(&self, circuit_name: &str, limit: usize) -> alloc::vec::Vec<crate::Event> {
+            self.inner.event_log(circuit_name, limit)
+        }
+
+        fn monotonic_time(&self) -> f64 {
+            self.inner.monotonic_time()
+        }
+
+        fn get_status(&self, circuit_name: &str) -> Option<CircuitStatus> {
+            self.try_get_status(circuit_name).ok().flatten()
+        }
+
+        fn set_status(&self, circuit_name: &str, status: CircuitStatus) {
+            let _ = self.try_set_status(circuit_name, status);
+        }
+
+        fn try_record_success(
+            &self,
+            circuit_name: &str,
+            duration: f64,
+        ) -> Result<(), StorageError> {
+            self.check()?;
+            self.inner.try_record_success(circuit_name, duration)
+        }
+
+        fn try_record_failure(
+            &self,
+            circuit_name: &str,
+            duration: f64,
+        ) -> Result<(), StorageError> {
+            self.check()?;
+            self.inner.try_record_failure(circuit_name, duration)
+        }
+
+        fn try_success_count(
+            &self,
+            circuit_name: &str,
+            window_seconds: f64,
+        ) -> Result<usize, StorageError> {
+            self.check()?;
+            self.inner.try_success_count(circuit_name, window_seconds)
+        }
+
+        fn try_failure_count(
+            &self,
+            circuit_name: &str,
+            window_seconds: f64,
+        ) -> Result<usize, StorageError> {
+            self.check()?;
+            self.inner.try_failure_count(circuit_name, window_seconds)
+        }
+
+        fn try_get_status(
+            &self,
+            circuit_name: &str,
+        ) -> Result<Option<CircuitStatus>, StorageError> {
+            self.check()?;
+            self.inner.try_get_status(circuit_name)
+        }
+
+        fn try_set_status(
+            &self,
+            circuit_name: &str,
+            status: CircuitStatus,
+        ) -> Result<(), StorageError> {
+            self.check()?;
+            self.inner.try_set_status(circuit_name, status)
+        }
+    }
+
+    #[test]
+    fn test_storage_failure_fail_open_keeps_calling() {
+        let storage = Arc::new(OutageStorage::default());
+        let mut circuit = CircuitBreaker::builder("test")
+            .storage(storage.clone())
+            .failure_threshold(1)
+            .build();
+
+        storage.set_down(true);
+        let _ = circuit.call(|| Err::<(), _>("error"));
+        assert!(circuit.is_closed(), "uncounted failures must not trip");
+        assert!(circuit.storage_error().is_some());
+
+        let result = circuit.call(|| Ok::<_, &str>("still served"));
+        assert_eq!(result.ok(), Some("still served"));
+    }
+
+    #[test]
+    fn test_storage_failure_fail_closed_rejects_until_recovery() {
+        let storage = Arc::new(OutageStorage::default());
+        let mut circuit = CircuitBreaker::builder("test")
+            .storage(storage.clone())
+            .storage_failure_policy(StorageFailurePolicy::FailClosed)
+            .build();
+
+        storage.set_down(true);
+        // The call that discovers the outage still returns its own result
+        assert!(circuit.call(|| Ok::<_, &str>("first")).is_ok());
+
+        let result = circuit.call(|| Ok::<_, &str>("rejected"));
+        match result {
+            Err(CircuitError::Storage { circuit, error }) => {
+                assert_eq!(circuit, "test");
+                assert_eq!(error, StorageError::Unavailable("outage".to_string()));
+            }
+            other => panic!("expected storage error, got {other:?}"),
+        }
+
+        storage.set_down(false);
+        assert!(circuit.call(|| Ok::<_, &str>("recovered")).is_ok());
+        assert!(circuit.storage_error().is_none());
+    }
+
+    #[test]
+    fn test_storage_failure_fallback_to_memory_still_trips() {
+        let storage = Arc::new(OutageStorage::default());
+        let mut circuit = CircuitBreaker::builder("test")
+            .storage(storage.clone())
+            .failure_threshold(2)
+            .storage_failure_policy(StorageFailurePolicy::FallbackToMemory)
+            .build();
+
+        storage.set_down(true);
+        let _ = circuit.call(|| Err::<(), _>("error 1"));
+        let _ = circuit.call(|| Err::<(), _>("error 2"));
+        assert!(circuit.is_open());
+        assert!(circuit.storage_error().is_none());
+    }
 }
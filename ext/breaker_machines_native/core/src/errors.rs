@@ -6,14 +6,56 @@ use core::error::Error;
 use core::fmt;
 
 /// Errors that can occur during circuit breaker operations
+///
+/// New variants and new fields on the existing ones may be added in minor
+/// releases, so match with a wildcard arm and `..` in struct patterns.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum CircuitError<E = Box<dyn Error + Send + Sync>> {
     /// Circuit is open, calls are being rejected
-    Open { circuit: String, opened_at: f64 },
+    #[non_exhaustive]
+    Open {
+        circuit: String,
+        /// Storage monotonic time when the circuit opened
+        opened_at: f64,
+        /// Seconds until the circuit lets a half-open probe through
+        ///
+        /// Infinite while the circuit is forced open.
+        retry_after_secs: f64,
+        /// Open timeout of this period (configured timeout after backoff and jitter)
+        timeout_secs: f64,
+    },
     /// Half-open request limit has been reached
+    #[non_exhaustive]
     HalfOpenLimitReached { circuit: String },
     /// Bulkhead is at capacity, cannot acquire permit
+    #[non_exhaustive]
     BulkheadFull { circuit: String, limit: usize },
+    /// A circuit this one depends on is open (see [`CircuitGroup`](crate::CircuitGroup))
+    #[non_exhaustive]
+    DependencyOpen { circuit: String, dependency: String },
+    /// [`failover`](crate::failover) was given no circuits to call through
+    NoBackends,
+    /// A [`CircuitGroup`](crate::CircuitGroup) has no circuit of that name
+    #[non_exhaustive]
+    UnknownCircuit { circuit: String },
+    /// Storage backend is failing and the circuit fails closed
+    #[non_exhaustive]
+    Storage {
+        circuit: String,
+        error: StorageError,
+    },
+    /// The wrapped operation did not finish within the call timeout
+    ///
+    /// The call is recorded as a failure, classified as a [`CallTimeout`].
+    #[non_exhaustive]
+    Timeout { circuit: String, timeout_secs: f64 },
+    /// Too many timed-out sync calls are still running on their workers
+    ///
+    /// Returned by `call_with_timeout` while `limit` (`max_abandoned_calls`)
+    /// abandoned workers have not returned; the operation is not run.
+    #[non_exhaustive]
+    TimeoutWorkersExhausted { circuit: String, limit: usize },
     /// The wrapped operation failed
     Execution(E),
 }
@@ -21,8 +63,15 @@ pub enum CircuitError<E = Box<dyn Error + Send + Sync>> {
 impl<E: fmt::Display> fmt::Display for CircuitError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            CircuitError::Open { circuit, opened_at } => {
-                write!(f, "Circuit '{circuit}' is open (opened at {opened_at})")
+            CircuitError::Open {
+                circuit,
+                retry_after_secs,
+                ..
+            } => {
+                write!(
+                    f,
+                    "Circuit '{circuit}' is open (retry after {retry_after_secs:.3}s)"
+                )
             }
             CircuitError::HalfOpenLimitReached { circuit } => {
                 write!(f, "Circuit '{circuit}' half-open request limit reached")
@@ -30,16 +79,117 @@ impl<E: fmt::Display> fmt::Display for CircuitError<E> {
             CircuitError::BulkheadFull { circuit, limit } => {
                 write!(f, "Circuit '{circuit}' bulkhead is full (limit: {limit})")
             }
+            CircuitError::DependencyOpen {
+                circuit,
+                dependency,
+            } => {
+                write!(
+                    f,
+                    "Circuit '{circuit}' depends on '{dependency}', which is open"
+                )
+            }
+            CircuitError::NoBackends => write!(f, "No circuits to fail over across"),
+            CircuitError::UnknownCircuit { circuit } => {
+                write!(f, "No circuit named '{circuit}'")
+            }
+            CircuitError::Storage { circuit, error } => {
+                write!(f, "Circuit '{circuit}' storage unavailable: {error}")
+            }
+            CircuitError::Timeout {
+                circuit,
+                timeout_secs,
+            } => {
+                write!(
+                    f,
+                    "Circuit '{circuit}' call timed out after {timeout_secs}s"
+                )
+            }
+            CircuitError::TimeoutWorkersExhausted { circuit, limit } => {
+                write!(
+                    f,
+                    "Circuit '{circuit}' has {limit} timed-out calls still running"
+                )
+            }
             CircuitError::Execution(e) => write!(f, "Circuit execution failed: {e}"),
         }
     }
 }
 
+impl<E> CircuitError<E> {
+    /// Seconds until a rejected call may be retried, e.g. for a `Retry-After` header
+    ///
+    /// Only [`CircuitError::Open`] carries a known wait.
+    pub fn retry_after_secs(&self) -> Option<f64> {
+        match self {
+            CircuitError::Open {
+                retry_after_secs, ..
+            } => Some(*retry_after_secs),
+            _ => None,
+        }
+    }
+}
+
 impl<E: Error + 'static> Error for CircuitError<E> {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
             CircuitError::Execution(e) => Some(e),
+            CircuitError::Storage { error, .. } => Some(error),
             _ => None,
         }
     }
 }
+
+/// Error a failure classifier sees for a call that ran past its timeout
+///
+/// A [`FailureClassifier`](crate::FailureClassifier) can downcast to it to
+/// treat timeouts apart from the operation's own errors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CallTimeout {
+    /// Timeout the call exceeded
+    pub timeout_secs: f64,
+}
+
+impl CallTimeout {
+    /// Error returned to the caller of the timed-out call
+    #[cfg(feature = "std")]
+    pub(crate) fn into_error<E>(self, circuit: String) -> CircuitError<E> {
+        CircuitError::Timeout {
+            circuit,
+            timeout_secs: self.timeout_secs,
+        }
+    }
+}
+
+impl fmt::Display for CallTimeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Call timed out after {}s", self.timeout_secs)
+    }
+}
+
+impl Error for CallTimeout {}
+
+/// Errors reported by a [`StorageBackend`](crate::StorageBackend)
+///
+/// Only backends that can actually fail (networked or chained ones) produce
+/// these; the in-memory backends always succeed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StorageError {
+    /// The backend could not be reached or rejected the operation
+    Unavailable(String),
+    /// The backend did not answer in time
+    Timeout,
+    /// Every backend in a fallback chain is marked unhealthy
+    AllBackendsUnhealthy,
+}
+
+impl fmt::Display for StorageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StorageError::Unavailable(reason) => write!(f, "Storage unavailable: {reason}"),
+            StorageError::Timeout => write!(f, "Storage operation timed out"),
+            StorageError::AllBackendsUnhealthy => write!(f, "All storage backends are unhealthy"),
+        }
+    }
+}
+
+impl Error for StorageError {}
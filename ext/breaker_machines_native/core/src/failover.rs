@@ -0,0 +1,204 @@
+//! Failover across replicas of one service
+//!
+//! Each replica gets its own circuit. [`failover`] tries them in order: open
+//! circuits are skipped without invoking anything, and a replica whose call
+//! fails hands over to the next one. The result reports which replica served
+//! the call.
+
+use crate::{CircuitBreaker, circuit::CallGate, errors::CircuitError};
+use alloc::string::String;
+
+/// Successful result of a failover call
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Served<T> {
+    /// Value returned by the operation
+    pub value: T,
+    /// Position of the serving circuit among those passed in
+    pub index: usize,
+    /// Name of the serving circuit
+    pub circuit: String,
+}
+
+/// Call through the first circuit that admits the call and succeeds
+///
+/// Circuits are tried in order. One that rejects the call (Open, bulkhead
+/// full, no HalfOpen probe slot left) is skipped before `operation` runs; one
+/// whose call fails records the failure and falls through to the next.
+/// `operation` receives the position of the circuit it runs behind, to pick
+/// the matching replica. If no circuit serves the call, the error from the
+/// last one is returned, or [`CircuitError::NoBackends`] if there are no
+/// circuits at all.
+///
+/// # Examples
+///
+/// ```rust
+/// use breaker_machines::{CircuitBreaker, failover};
+///
+/// let replicas = ["db-1", "db-2"];
+/// let mut circuits: Vec<_> = replicas
+///     .iter()
+///     .map(|name| CircuitBreaker::builder(*name).build())
+///     .collect();
+/// circuits[0].force_open();
+///
+/// let served = failover(&mut circuits, |index| Ok::<_, String>(replicas[index])).unwrap();
+/// assert_eq!(served.value, "db-2");
+/// assert_eq!(served.circuit, "db-2");
+/// ```
+pub fn failover<'a, I, F, T, E: 'static>(
+    circuits: I,
+    mut operation: F,
+) -> Result<Served<T>, CircuitError<E>>
+where
+    I: IntoIterator<Item = &'a mut CircuitBreaker>,
+    F: FnMut(usize) -> Result<T, E>,
+{
+    let mut last_error = None;
+
+    for (index, circuit) in circuits.into_iter().enumerate() {
+        let result = match circuit.prepare_call() {
+            Ok(CallGate::Execute(permit)) => circuit.execute_call(permit, || operation(index)),
+            Ok(CallGate::Open { context, .. }) => Err(context.into_open_error()),
+            Err(error) => Err(error),
+        };
+
+        match result {
+            Ok(value) => {
+                return Ok(Served {
+                    value,
+                    index,
+                    circuit: String::from(circuit.name()),
+                });
+            }
+            Err(error) => last_error = Some(error),
+        }
+    }
+
+    Err(last_error.unwrap_or(CircuitError::NoBackends))
+}
+
+/// Async counterpart of [`failover`], over async circuits
+#[cfg(feature = "async")]
+pub async fn failover_async<'a, I, F, Fut, T, E: 'static>(
+    circuits: I,
+    mut operation: F,
+) -> Result<Served<T>, CircuitError<E>>
+where
+    I: IntoIterator<Item = &'a crate::AsyncCircuitBreaker>,
+    F: FnMut(usize) -> Fut,
+    Fut: core::future::Future<Output = Result<T, E>>,
+{
+    let mut last_error = None;
+
+    for (index, circuit) in circuits.into_iter().enumerate() {
+        // The async call only runs the operation once its gate admits it
+        match circuit.call(|| operation(index)).await {
+            Ok(value) => {
+                return Ok(Served {
+                    value,
+                    index,
+                    circuit: circuit.name(),
+                });
+            }
+            Err(error) => last_error = Some(error),
+        }
+    }
+
+    Err(last_error.unwrap_or(CircuitError::NoBackends))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn circuits() -> Vec<CircuitBreaker> {
+        ["primary", "secondary", "tertiary"]
+            .into_iter()
+            .map(|name| CircuitBreaker::builder(name).failure_threshold(1).build())
+            .collect()
+    }
+
+    #[test]
+    fn skips_open_circuits_without_invoking() {
+        let mut circuits = circuits();
+        circuits[0].force_open();
+        let mut invoked = Vec::new();
+
+        let served = failover(&mut circuits, |index| {
+            invoked.push(index);
+            Ok::<_, &str>("ok")
+        })
+        .unwrap();
+
+        assert_eq!(invoked, [1]);
+        assert_eq!((served.index, served.circuit.as_str()), (1, "secondary"));
+    }
+
+    #[test]
+    fn falls_through_on_execution_errors() {
+        let mut circuits = circuits();
+
+        let served = failover(&mut circuits, |index| match index {
+            0 => Err("primary down"),
+            _ => Ok(index),
+        })
+        .unwrap();
+
+        assert_eq!(served.value, 1);
+        assert!(circuits[0].is_open(), "the failure is recorded");
+        assert_eq!(circuits[1].stats().success_count, 1);
+        assert_eq!(circuits[2].stats().success_count, 0);
+    }
+
+    #[test]
+    fn returns_the_last_error_when_nothing_serves() {
+        let mut circuits = circuits();
+        circuits[2].force_open();
+
+        let result = failover(&mut circuits, |_| Err::<(), _>("down"));
+        assert!(matches!(
+            result,
+            Err(CircuitError::Open { ref circuit, .. }) if circuit == "tertiary"
+        ));
+    }
+
+    #[test]
+    fn no_circuits_is_an_error() {
+        let mut invoked = false;
+
+        let result = failover(&mut Vec::new(), |_| {
+            invoked = true;
+            Ok::<_, &str>("ok")
+        });
+        assert!(matches!(result, Err(CircuitError::NoBackends)));
+        assert!(!invoked);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn async_failover_skips_open_circuits() {
+        let circuits: Vec<_> = ["primary", "secondary"]
+            .into_iter()
+            .map(|name| crate::AsyncCircuitBreaker::builder(name).build_async())
+            .collect();
+        circuits[0].force_open();
+
+        let served = pollster::block_on(failover_async(&circuits, |index| async move {
+            assert_eq!(index, 1);
+            Ok::<_, &str>("served")
+        }))
+        .unwrap();
+        assert_eq!(
+            (served.value, served.circuit.as_str()),
+            ("served", "secondary")
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn async_failover_without_circuits_is_an_error() {
+        let result = pollster::block_on(failover_async([], |_| async { Ok::<_, &str>("ok") }));
+        assert!(matches!(result, Err(CircuitError::NoBackends)));
+    }
+}
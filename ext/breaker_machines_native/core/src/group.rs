@@ -0,0 +1,280 @@
+//! Groups of related circuits
+//!
+//! A [`CircuitGroup`] owns the circuits of one subsystem and knows which of
+//! them depend on which. A call to a circuit whose dependencies are open is
+//! rejected with [`CircuitError::DependencyOpen`] before the circuit itself is
+//! consulted, mirroring the Ruby `CircuitGroup`'s `depends_on`.
+
+use crate::{CircuitBreaker, circuit::IntoCallOptions, errors::CircuitError};
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Circuits managed together, with dependencies between them
+///
+/// Circuits are keyed by their own names. A dependency counts as open when it
+/// is Open (forced or not), when any of its own dependencies is, or when no
+/// circuit of that name is in the group. HalfOpen dependencies let calls
+/// through, so a recovering service can be probed.
+///
+/// # Examples
+///
+/// ```rust
+/// use breaker_machines::{CircuitBreaker, CircuitError, CircuitGroup};
+///
+/// let mut group = CircuitGroup::new("checkout");
+/// group.add(CircuitBreaker::builder("database").build());
+/// group.add(CircuitBreaker::builder("payments").build());
+/// group.depends_on("payments", ["database"]);
+///
+/// group.get_mut("database").unwrap().force_open();
+///
+/// let result = group.call("payments", || Ok::<_, String>("charged"));
+/// assert!(matches!(result, Err(CircuitError::DependencyOpen { .. })));
+/// ```
+pub struct CircuitGroup {
+    name: String,
+    circuits: BTreeMap<String, CircuitBreaker>,
+    /// Direct dependencies, by dependent circuit name
+    dependencies: BTreeMap<String, Vec<String>>,
+}
+
+impl core::fmt::Debug for CircuitGroup {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CircuitGroup")
+            .field("name", &self.name)
+            .field("status", &self.status())
+            .field("dependencies", &self.dependencies)
+            .finish()
+    }
+}
+
+impl CircuitGroup {
+    /// Create an empty group
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            circuits: BTreeMap::new(),
+            dependencies: BTreeMap::new(),
+        }
+    }
+
+    /// Group name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Add a circuit under its own name, replacing any circuit of that name
+    ///
+    /// Dependencies already declared for the name are kept.
+    pub fn add(&mut self, circuit: CircuitBreaker) -> &mut Self {
+        self.circuits.insert(String::from(circuit.name()), circuit);
+        self
+    }
+
+    /// Declare that calls to `circuit` need `dependencies` to be closed
+    ///
+    /// Dependencies may be added to the group later; until then they count as
+    /// open.
+    pub fn depends_on<I, S>(&mut self, circuit: &str, dependencies: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let declared = self.dependencies.entry(String::from(circuit)).or_default();
+        for dependency in dependencies {
+            let dependency = dependency.into();
+            if !declared.contains(&dependency) {
+                declared.push(dependency);
+            }
+        }
+        self
+    }
+
+    /// Direct dependencies declared for a circuit
+    pub fn dependencies(&self, circuit: &str) -> &[String] {
+        self.dependencies.get(circuit).map_or(&[], Vec::as_slice)
+    }
+
+    /// Look up a circuit of the group
+    pub fn get(&self, circuit: &str) -> Option<&CircuitBreaker> {
+        self.circuits.get(circuit)
+    }
+
+    /// Look up a circuit of the group for direct use
+    ///
+    /// Calls made this way skip the dependency check.
+    pub fn get_mut(&mut self, circuit: &str) -> Option<&mut CircuitBreaker> {
+        self.circuits.get_mut(circuit)
+    }
+
+    /// Call through `circuit` if none of its dependencies is open
+    ///
+    /// Returns [`CircuitError::UnknownCircuit`] if the group has no circuit
+    /// named `circuit`.
+    pub fn call<I, T, E: 'static>(&mut self, circuit: &str, input: I) -> Result<T, CircuitError<E>>
+    where
+        I: IntoCallOptions<T, E>,
+    {
+        if self.circuits.contains_key(circuit)
+            && let Some(dependency) = self.open_dependency(circuit)
+        {
+            return Err(CircuitError::DependencyOpen {
+                circuit: String::from(circuit),
+                dependency,
+            });
+        }
+
+        match self.circuits.get_mut(circuit) {
+            Some(target) => target.call(input),
+            None => Err(CircuitError::UnknownCircuit {
+                circuit: String::from(circuit),
+            }),
+        }
+    }
+
+    /// Whether calls to `circuit` would pass the dependency check
+    pub fn dependencies_met(&self, circuit: &str) -> bool {
+        self.open_dependency(circuit).is_none()
+    }
+
+    /// The first dependency of `circuit`, direct or transitive, that is open
+    pub fn open_dependency(&self, circuit: &str) -> Option<String> {
+        let mut visited = Vec::new();
+        self.find_open_dependency(circuit, &mut visited)
+            .map(String::from)
+    }
+
+    fn find_open_dependency<'a>(
+        &'a self,
+        circuit: &'a str,
+        visited: &mut Vec<&'a str>,
+    ) -> Option<&'a str> {
+        // A dependency cycle adds nothing beyond the circuits already checked
+        if visited.contains(&circuit) {
+            return None;
+        }
+        visited.push(circuit);
+
+        self.dependencies(circuit).iter().find_map(|dependency| {
+            match self.circuits.get(dependency.as_str()) {
+                Some(dep) if !dep.is_open() => self.find_open_dependency(dependency, visited),
+                _ => Some(dependency.as_str()),
+            }
+        })
+    }
+
+    /// State name of every circuit, by circuit name
+    pub fn status(&self) -> BTreeMap<&str, &'static str> {
+        self.circuits
+            .iter()
+            .map(|(name, circuit)| (name.as_str(), circuit.state_name()))
+            .collect()
+    }
+
+    /// Whether no circuit of the group is open
+    pub fn all_healthy(&self) -> bool {
+        !self.any_open()
+    }
+
+    /// Whether any circuit of the group is open
+    pub fn any_open(&self) -> bool {
+        self.circuits.values().any(CircuitBreaker::is_open)
+    }
+
+    /// Reset every circuit of the group
+    pub fn reset_all(&mut self) {
+        self.circuits.values_mut().for_each(CircuitBreaker::reset);
+    }
+
+    /// Force every circuit of the group open
+    pub fn force_open_all(&mut self) {
+        self.circuits
+            .values_mut()
+            .for_each(CircuitBreaker::force_open);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group() -> CircuitGroup {
+        let mut group = CircuitGroup::new("checkout");
+        for name in ["database", "cache", "payments", "orders"] {
+            group.add(CircuitBreaker::builder(name).failure_threshold(1).build());
+        }
+        group
+            .depends_on("payments", ["database"])
+            .depends_on("orders", ["payments", "cache"]);
+        group
+    }
+
+    #[test]
+    fn open_dependency_rejects_calls() {
+        let mut group = group();
+        let _ = group.call("database", || Err::<(), _>("down"));
+        assert!(group.get("database").unwrap().is_open());
+
+        let result = group.call("payments", || Ok::<_, &str>("charged"));
+        assert!(matches!(
+            result,
+            Err(CircuitError::DependencyOpen { ref circuit, ref dependency })
+                if circuit == "payments" && dependency == "database"
+        ));
+        // The rejection isn't counted against the circuit itself
+        assert_eq!(group.get("payments").unwrap().stats().failure_count, 0);
+
+        // Transitive: orders -> payments -> database
+        assert_eq!(group.open_dependency("orders").as_deref(), Some("database"));
+        assert!(group.call("cache", || Ok::<_, &str>("hit")).is_ok());
+    }
+
+    #[test]
+    fn unknown_circuit_is_an_error() {
+        let mut group = group();
+        group.depends_on("search", ["database"]);
+
+        let result = group.call("search", || Ok::<_, &str>("found"));
+        assert!(matches!(
+            result,
+            Err(CircuitError::UnknownCircuit { ref circuit }) if circuit == "search"
+        ));
+    }
+
+    #[test]
+    fn missing_dependency_counts_as_open() {
+        let mut group = group();
+        group.depends_on("cache", ["redis"]);
+
+        assert_eq!(group.open_dependency("cache").as_deref(), Some("redis"));
+        assert!(!group.dependencies_met("orders"));
+
+        group.add(CircuitBreaker::builder("redis").build());
+        assert!(group.dependencies_met("orders"));
+    }
+
+    #[test]
+    fn dependency_cycles_terminate() {
+        let mut group = group();
+        group.depends_on("database", ["orders"]);
+
+        assert!(group.dependencies_met("orders"));
+        group.get_mut("cache").unwrap().force_open();
+        assert_eq!(group.open_dependency("database").as_deref(), Some("cache"));
+    }
+
+    #[test]
+    fn group_wide_status_and_reset() {
+        let mut group = group();
+        assert!(group.all_healthy());
+
+        group.force_open_all();
+        assert!(group.any_open());
+        assert!(group.status().values().all(|state| *state == "ForcedOpen"));
+
+        group.reset_all();
+        assert!(group.all_healthy());
+        assert_eq!(group.status()["payments"], "Closed");
+    }
+}
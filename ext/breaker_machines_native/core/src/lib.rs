@@ -40,26 +40,58 @@ extern crate std;
 pub mod builder;
 pub mod bulkhead;
 pub mod callbacks;
+pub mod cascade;
 pub mod circuit;
 pub mod classifier;
 pub mod errors;
+pub mod failover;
+pub mod group;
+pub mod recovery;
+#[cfg(feature = "std")]
+pub mod registry;
+#[cfg(feature = "std")]
+pub mod shared_circuit;
 pub mod storage;
 pub mod time;
 
 #[cfg(feature = "async")]
 pub mod async_circuit;
+#[cfg(feature = "async")]
+mod timer;
 
 #[cfg(feature = "async")]
-pub use async_circuit::{AsyncCallOptions, AsyncCircuitBreaker};
+pub use async_circuit::{AsyncCallOptions, AsyncCircuitBreaker, HedgeOptions};
 pub use builder::CircuitBuilder;
 pub use bulkhead::{BulkheadGuard, BulkheadSemaphore};
-pub use circuit::{CallOptions, CircuitBreaker, Config, FallbackContext};
+pub use cascade::CascadeTarget;
+pub use circuit::{
+    CallOptions, CircuitBreaker, CircuitOverride, CircuitState, CircuitStats, Config,
+    FallbackContext, FallbackReason, SlidingWindow, StorageFailurePolicy,
+};
 pub use classifier::{DefaultClassifier, FailureClassifier, FailureContext, PredicateClassifier};
-pub use errors::CircuitError;
-pub use storage::{MemoryStorage, NullStorage, StorageBackend};
-pub use time::{Clock, ZeroClock};
+pub use errors::{CallTimeout, CircuitError, StorageError};
+#[cfg(feature = "async")]
+pub use failover::failover_async;
+pub use failover::{Served, failover};
+pub use group::CircuitGroup;
+pub use recovery::{PredicateRecoveryGuard, RecoveryContext, RecoveryGuard};
+#[cfg(feature = "std")]
+pub use registry::{CircuitRegistry, RegistryStats};
+#[cfg(feature = "std")]
+pub use shared_circuit::SharedCircuitBreaker;
+#[cfg(feature = "resp")]
+pub use storage::RespStorage;
+#[cfg(all(feature = "shared-file", unix))]
+pub use storage::SharedFileStorage;
+#[cfg(feature = "async")]
+pub use storage::{AsyncStorageBackend, StorageFuture};
+pub use storage::{
+    BucketedStorage, CallCounts, CircuitStatus, FallbackChainStorage, MemoryStorage, NullStorage,
+    StorageBackend,
+};
 #[cfg(feature = "std")]
 pub use time::SystemClock;
+pub use time::{Clock, ZeroClock};
 
 /// Event type for circuit breaker operations
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
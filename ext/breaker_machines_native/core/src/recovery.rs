@@ -0,0 +1,94 @@
+//! Recovery guards for coordinated recovery
+//!
+//! A circuit's Open timeout only says when it *may* probe again. A
+//! [`RecoveryGuard`] can hold it back further, for example while a critical
+//! dependency or an external health check still reports trouble. This mirrors
+//! the Ruby `CoordinatedStateManagement`'s `recovery_allowed?` and
+//! `reset_allowed?`.
+
+/// Context provided to recovery guards
+#[derive(Debug, Clone, Copy)]
+pub struct RecoveryContext<'a> {
+    /// Circuit name
+    pub circuit_name: &'a str,
+    /// Consecutive Open periods without a successful close, starting at 1
+    pub attempt: u32,
+}
+
+/// Trait for gating recovery - decides whether a circuit may leave Open
+///
+/// Guards are consulted while the circuit is locked, so they should read
+/// cheap, non-blocking signals such as atomics rather than lock other
+/// circuits that might consult this one in turn.
+///
+/// # Examples
+///
+/// ```rust
+/// use breaker_machines::{RecoveryContext, RecoveryGuard};
+/// use std::sync::atomic::{AtomicBool, Ordering};
+///
+/// #[derive(Debug)]
+/// struct DatabaseHealth(AtomicBool);
+///
+/// impl RecoveryGuard for DatabaseHealth {
+///     fn recovery_allowed(&self, _ctx: &RecoveryContext<'_>) -> bool {
+///         // Don't probe the API while its database is down
+///         self.0.load(Ordering::Acquire)
+///     }
+/// }
+/// ```
+pub trait RecoveryGuard: Send + Sync + core::fmt::Debug {
+    /// Determine if an Open circuit whose timeout has elapsed may move to
+    /// HalfOpen and let probes through
+    fn recovery_allowed(&self, ctx: &RecoveryContext<'_>) -> bool;
+
+    /// Determine if a HalfOpen circuit whose probes succeeded may close
+    ///
+    /// Defaults to [`recovery_allowed`](Self::recovery_allowed). While it
+    /// returns `false` the circuit stays HalfOpen, rejecting calls once its
+    /// probes are used up, and closes on the first call after it relents.
+    fn reset_allowed(&self, ctx: &RecoveryContext<'_>) -> bool {
+        self.recovery_allowed(ctx)
+    }
+}
+
+/// Predicate-based recovery guard using a closure
+///
+/// The predicate gates both the Open -> HalfOpen and HalfOpen -> Closed
+/// transitions.
+pub struct PredicateRecoveryGuard<F>
+where
+    F: Fn(&RecoveryContext<'_>) -> bool + Send + Sync,
+{
+    predicate: F,
+}
+
+impl<F> PredicateRecoveryGuard<F>
+where
+    F: Fn(&RecoveryContext<'_>) -> bool + Send + Sync,
+{
+    /// Create a new predicate-based recovery guard
+    pub fn new(predicate: F) -> Self {
+        Self { predicate }
+    }
+}
+
+impl<F> RecoveryGuard for PredicateRecoveryGuard<F>
+where
+    F: Fn(&RecoveryContext<'_>) -> bool + Send + Sync,
+{
+    fn recovery_allowed(&self, ctx: &RecoveryContext<'_>) -> bool {
+        (self.predicate)(ctx)
+    }
+}
+
+impl<F> core::fmt::Debug for PredicateRecoveryGuard<F>
+where
+    F: Fn(&RecoveryContext<'_>) -> bool + Send + Sync,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PredicateRecoveryGuard")
+            .field("predicate", &"<closure>")
+            .finish()
+    }
+}
@@ -0,0 +1,367 @@
+//! Registry of named circuits shared across an application
+//!
+//! Services that create circuits on demand (one per tenant, host or endpoint)
+//! look them up by name in a [`CircuitRegistry`] instead of threading handles
+//! through their code. Circuits created on demand are *dynamic*: once nothing
+//! has used them for a while they can be evicted with
+//! [`CircuitRegistry::evict_idle`].
+
+use crate::{
+    CircuitBreaker, CircuitBuilder, CircuitState, CircuitStats, Clock, SharedCircuitBreaker,
+    SystemClock,
+};
+use std::boxed::Box;
+use std::collections::HashMap;
+use std::string::String;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{PoisonError, RwLock};
+use std::vec::Vec;
+
+type Template = Box<dyn Fn(CircuitBuilder) -> CircuitBuilder + Send + Sync>;
+
+struct Entry {
+    circuit: SharedCircuitBreaker,
+    /// Created by `get_or_create`, and so eligible for eviction
+    dynamic: bool,
+    /// Clock reading of the last lookup, as `f64` bits
+    last_used: AtomicU64,
+}
+
+impl Entry {
+    fn new(circuit: SharedCircuitBreaker, dynamic: bool, now: f64) -> Self {
+        Self {
+            circuit,
+            dynamic,
+            last_used: AtomicU64::new(now.to_bits()),
+        }
+    }
+
+    fn touch(&self, now: f64) -> SharedCircuitBreaker {
+        self.last_used.store(now.to_bits(), Ordering::Relaxed);
+        self.circuit.clone()
+    }
+
+    fn last_used(&self) -> f64 {
+        f64::from_bits(self.last_used.load(Ordering::Relaxed))
+    }
+}
+
+/// Aggregate view of every registered circuit, returned by [`CircuitRegistry::stats`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RegistryStats {
+    /// Registered circuits
+    pub total: usize,
+    /// Circuits in the Closed state
+    pub closed: usize,
+    /// Circuits in the Open state
+    pub open: usize,
+    /// Circuits in the HalfOpen state
+    pub half_open: usize,
+    /// Failures within the sliding windows of all circuits
+    pub failure_count: usize,
+    /// Successes within the sliding windows of all circuits
+    pub success_count: usize,
+}
+
+/// Thread-safe registry handing out shared circuits by name
+///
+/// # Examples
+///
+/// ```rust
+/// use breaker_machines::CircuitRegistry;
+///
+/// let registry = CircuitRegistry::with_template(|builder| builder.failure_threshold(3));
+///
+/// let circuit = registry.get_or_create("tenant-42");
+/// let result = circuit.call(|| Ok::<_, String>("ok"));
+/// assert!(result.is_ok());
+///
+/// // Drop per-tenant circuits nobody has looked up for an hour
+/// drop(circuit);
+/// registry.evict_idle(3600.0);
+/// ```
+pub struct CircuitRegistry {
+    template: Template,
+    circuits: RwLock<HashMap<String, Entry>>,
+    clock: Box<dyn Clock>,
+}
+
+impl Default for CircuitRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for CircuitRegistry {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CircuitRegistry")
+            .field("circuits", &self.names())
+            .field("clock", &self.clock)
+            .finish()
+    }
+}
+
+impl CircuitRegistry {
+    /// Create a registry whose circuits use the builder defaults
+    pub fn new() -> Self {
+        Self::with_template(|builder| builder)
+    }
+
+    /// Create a registry that configures each circuit it creates with `template`
+    ///
+    /// The template receives a builder already named after the circuit.
+    pub fn with_template<F>(template: F) -> Self
+    where
+        F: Fn(CircuitBuilder) -> CircuitBuilder + Send + Sync + 'static,
+    {
+        Self {
+            template: Box::new(template),
+            circuits: RwLock::new(HashMap::new()),
+            clock: Box::new(SystemClock::new()),
+        }
+    }
+
+    /// Use `clock` to measure how long circuits have been idle
+    pub fn with_clock(mut self, clock: Box<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Register a circuit built elsewhere under its own name
+    ///
+    /// Registered circuits are never evicted. Replaces any circuit already
+    /// registered under that name.
+    pub fn register(&self, circuit: CircuitBreaker) -> SharedCircuitBreaker {
+        let name = String::from(circuit.name());
+        let circuit = SharedCircuitBreaker::from_circuit(circuit);
+        let entry = Entry::new(circuit.clone(), false, self.clock.now_secs());
+        self.write().insert(name, entry);
+        circuit
+    }
+
+    /// Look up a circuit by name
+    pub fn get(&self, name: &str) -> Option<SharedCircuitBreaker> {
+        let now = self.clock.now_secs();
+        self.read().get(name).map(|entry| entry.touch(now))
+    }
+
+    /// Look up a circuit by name, creating it from the template if missing
+    pub fn get_or_create(&self, name: &str) -> SharedCircuitBreaker {
+        if let Some(circuit) = self.get(name) {
+            return circuit;
+        }
+
+        // Build without the lock: restoring the circuit reads its status
+        // from storage, which may be a network round trip
+        let circuit = (self.template)(CircuitBuilder::new(name)).build();
+
+        let now = self.clock.now_secs();
+        let mut circuits = self.write();
+        // Another thread may have created it while this one was building
+        if let Some(entry) = circuits.get(name) {
+            let existing = entry.touch(now);
+            drop(circuits);
+            circuit.discard();
+            return existing;
+        }
+        let circuit = SharedCircuitBreaker::from_circuit(circuit);
+        circuits.insert(String::from(name), Entry::new(circuit.clone(), true, now));
+        circuit
+    }
+
+    /// Remove a circuit, returning it if it was registered
+    pub fn remove(&self, name: &str) -> Option<SharedCircuitBreaker> {
+        self.write().remove(name).map(|entry| entry.circuit)
+    }
+
+    /// Names of all registered circuits
+    pub fn names(&self) -> Vec<String> {
+        self.read().keys().cloned().collect()
+    }
+
+    /// Number of registered circuits
+    pub fn len(&self) -> usize {
+        self.read().len()
+    }
+
+    /// Whether no circuits are registered
+    pub fn is_empty(&self) -> bool {
+        self.read().is_empty()
+    }
+
+    /// Stats of every registered circuit, by name
+    pub fn all_stats(&self) -> Vec<(String, CircuitStats)> {
+        self.read()
+            .iter()
+            .map(|(name, entry)| (name.clone(), entry.circuit.stats()))
+            .collect()
+    }
+
+    /// Circuit counts by state and summed call counts across the registry
+    pub fn stats(&self) -> RegistryStats {
+        self.all_stats()
+            .into_iter()
+            .fold(RegistryStats::default(), |mut totals, (_, stats)| {
+                totals.total += 1;
+                match stats.state {
+                    CircuitState::Closed => totals.closed += 1,
+                    CircuitState::Open => totals.open += 1,
+                    CircuitState::HalfOpen => totals.half_open += 1,
+                }
+                totals.failure_count += stats.failure_count;
+                totals.success_count += stats.success_count;
+                totals
+            })
+    }
+
+    /// Force the named circuit open; returns whether it is registered
+    pub fn force_open(&self, name: &str) -> bool {
+        self.with_circuit(name, SharedCircuitBreaker::force_open)
+    }
+
+    /// Force the named circuit closed; returns whether it is registered
+    pub fn force_close(&self, name: &str) -> bool {
+        self.with_circuit(name, SharedCircuitBreaker::force_close)
+    }
+
+    /// Reset the named circuit; returns whether it is registered
+    pub fn reset(&self, name: &str) -> bool {
+        self.with_circuit(name, SharedCircuitBreaker::reset)
+    }
+
+    /// Remove dynamic circuits not looked up for `max_idle_secs`
+    ///
+    /// A circuit whose handle is still held outside the registry stays, so
+    /// callers never end up with a circuit the registry has forgotten. Returns
+    /// the number of circuits evicted.
+    pub fn evict_idle(&self, max_idle_secs: f64) -> usize {
+        let cutoff = self.clock.now_secs() - max_idle_secs;
+        let mut circuits = self.write();
+        let before = circuits.len();
+        circuits.retain(|_, entry| {
+            !entry.dynamic || entry.last_used() > cutoff || entry.circuit.handle_count() > 1
+        });
+        before - circuits.len()
+    }
+
+    /// Remove every circuit
+    pub fn clear(&self) {
+        self.write().clear();
+    }
+
+    fn with_circuit(&self, name: &str, f: impl FnOnce(&SharedCircuitBreaker)) -> bool {
+        let Some(circuit) = self.get(name) else {
+            return false;
+        };
+        f(&circuit);
+        true
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, HashMap<String, Entry>> {
+        self.circuits.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, HashMap<String, Entry>> {
+        self.circuits
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::test_support::ManualClock;
+
+    #[test]
+    fn get_or_create_returns_the_same_circuit() {
+        let registry = CircuitRegistry::with_template(|builder| builder.failure_threshold(1));
+
+        let first = registry.get_or_create("tenant-1");
+        let _ = first.call(|| Err::<(), _>("down"));
+
+        let second = registry.get_or_create("tenant-1");
+        assert!(second.is_open());
+        assert_eq!(registry.len(), 1);
+        assert!(registry.get("tenant-2").is_none());
+    }
+
+    #[test]
+    fn get_or_create_builds_without_blocking_lookups() {
+        use std::sync::atomic::AtomicUsize;
+        use std::sync::{Arc, Mutex, mpsc};
+
+        let (building, started) = mpsc::channel::<()>();
+        let (finish, release) = mpsc::channel::<()>();
+        let (building, release) = (Mutex::new(building), Mutex::new(release));
+        let built = AtomicUsize::new(0);
+        let registry = Arc::new(CircuitRegistry::with_template(move |builder| {
+            // The second circuit built ("slow") waits to be released
+            if built.fetch_add(1, Ordering::SeqCst) == 1 {
+                building.lock().unwrap().send(()).unwrap();
+                release.lock().unwrap().recv().unwrap();
+            }
+            builder
+        }));
+        registry.get_or_create("fast");
+
+        let creating = {
+            let registry = Arc::clone(&registry);
+            std::thread::spawn(move || registry.get_or_create("slow"))
+        };
+        started.recv().unwrap();
+
+        // The template is still running for "slow"
+        assert!(registry.get("fast").is_some());
+        assert!(registry.get("slow").is_none());
+
+        finish.send(()).unwrap();
+        let slow = creating.join().unwrap();
+        slow.force_open();
+        assert!(registry.get_or_create("slow").is_open());
+    }
+
+    #[test]
+    fn stats_aggregate_all_circuits() {
+        let registry = CircuitRegistry::with_template(|builder| builder.failure_threshold(2));
+        let _ = registry.get_or_create("a").call(|| Ok::<_, &str>("ok"));
+        let _ = registry.get_or_create("b").call(|| Err::<(), _>("error"));
+        registry.register(CircuitBreaker::builder("c").build());
+        assert!(registry.force_open("c"));
+        assert!(!registry.force_open("missing"));
+
+        let stats = registry.stats();
+        assert_eq!(stats.total, 3);
+        assert_eq!(stats.closed, 2);
+        assert_eq!(stats.open, 1);
+        assert_eq!(stats.failure_count, 1);
+        assert_eq!(stats.success_count, 1);
+
+        let mut names = registry.names();
+        names.sort();
+        assert_eq!(names, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn evict_idle_removes_only_unused_dynamic_circuits() {
+        let clock = ManualClock::default();
+        let registry = CircuitRegistry::new().with_clock(Box::new(clock.clone()));
+
+        registry.register(CircuitBreaker::builder("static").build());
+        drop(registry.get_or_create("idle"));
+        let held = registry.get_or_create("held");
+        clock.set(50.0);
+        drop(registry.get_or_create("recent"));
+
+        clock.set(100.0);
+        assert_eq!(registry.evict_idle(60.0), 1);
+
+        let mut names = registry.names();
+        names.sort();
+        assert_eq!(names, ["held", "recent", "static"]);
+
+        drop(held);
+        assert_eq!(registry.evict_idle(60.0), 1);
+        assert!(registry.get("held").is_none());
+    }
+}
@@ -0,0 +1,616 @@
+//! Thread-safe circuit breaker handle for synchronous code.
+//!
+//! [`CircuitBreaker::call`] takes `&mut self`, so sharing one breaker across
+//! threads would need a mutex held for the whole protected call. A
+//! [`SharedCircuitBreaker`] only locks the circuit to admit the call and to
+//! record its outcome; the operation itself runs unlocked, as with
+//! [`AsyncCircuitBreaker`](crate::AsyncCircuitBreaker).
+//!
+//! While the circuit is Closed, successful calls skip the lock altogether:
+//! the handle reads the state from an atomic and records the success straight
+//! into storage. Failures and slow calls, which may trip the circuit, still go
+//! through the state machine.
+
+use crate::{
+    CallOptions, CascadeTarget, CircuitBreaker, CircuitBuilder, CircuitOverride, CircuitStats,
+    Config, FallbackContext,
+    circuit::{CallGate, FastPath, IntoCallOptions, run_on_worker},
+    errors::{CircuitError, StorageError},
+};
+use std::string::String;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// Locked circuit that republishes its fast-path flag when released
+pub(crate) struct CircuitGuard<'a> {
+    circuit: MutexGuard<'a, CircuitBreaker>,
+    fast: &'a FastPath,
+}
+
+impl<'a> CircuitGuard<'a> {
+    pub(crate) fn lock(inner: &'a Mutex<CircuitBreaker>, fast: &'a FastPath) -> Self {
+        Self {
+            circuit: inner.lock().unwrap_or_else(PoisonError::into_inner),
+            fast,
+        }
+    }
+}
+
+impl core::ops::Deref for CircuitGuard<'_> {
+    type Target = CircuitBreaker;
+
+    fn deref(&self) -> &CircuitBreaker {
+        &self.circuit
+    }
+}
+
+impl core::ops::DerefMut for CircuitGuard<'_> {
+    fn deref_mut(&mut self) -> &mut CircuitBreaker {
+        &mut self.circuit
+    }
+}
+
+impl Drop for CircuitGuard<'_> {
+    fn drop(&mut self) {
+        self.fast.publish(&self.circuit);
+    }
+}
+
+/// Releases a reserved half-open probe slot if the operation panics
+struct HalfOpenProbe<'a> {
+    circuit: &'a SharedCircuitBreaker,
+    active: bool,
+}
+
+impl HalfOpenProbe<'_> {
+    fn disarm(&mut self) {
+        self.active = false;
+    }
+}
+
+impl Drop for HalfOpenProbe<'_> {
+    fn drop(&mut self) {
+        if self.active {
+            self.circuit.lock_inner().release_half_open_probe();
+        }
+    }
+}
+
+/// Cloneable, `Send + Sync` handle to a circuit breaker.
+///
+/// Clones share one circuit. Calls from different threads run concurrently;
+/// the internal mutex is held only while the call is admitted and while its
+/// outcome is recorded.
+///
+/// # Examples
+///
+/// ```rust
+/// use breaker_machines::CircuitBreaker;
+///
+/// let circuit = CircuitBreaker::builder("api").failure_threshold(5).build_shared();
+///
+/// let worker = {
+///     let circuit = circuit.clone();
+///     std::thread::spawn(move || circuit.call(|| Ok::<_, String>("from worker")))
+/// };
+///
+/// assert!(circuit.call(|| Ok::<_, String>("from main")).is_ok());
+/// assert!(worker.join().unwrap().is_ok());
+/// ```
+#[derive(Clone)]
+pub struct SharedCircuitBreaker {
+    inner: Arc<Mutex<CircuitBreaker>>,
+    fast: Arc<FastPath>,
+}
+
+impl core::fmt::Debug for SharedCircuitBreaker {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let circuit = self.lock_inner();
+        f.debug_struct("SharedCircuitBreaker")
+            .field("name", &circuit.name())
+            .field("state", &circuit.state_name())
+            .finish()
+    }
+}
+
+impl SharedCircuitBreaker {
+    /// Create a new shared circuit breaker.
+    pub fn new(name: String, config: Config) -> Self {
+        Self::from_circuit(CircuitBreaker::new(name, config))
+    }
+
+    /// Create a builder; finish it with [`CircuitBuilder::build_shared`].
+    pub fn builder(name: impl Into<String>) -> CircuitBuilder {
+        CircuitBreaker::builder(name)
+    }
+
+    /// Wrap an existing circuit breaker.
+    pub fn from_circuit(circuit: CircuitBreaker) -> Self {
+        Self {
+            fast: Arc::new(FastPath::new(&circuit)),
+            inner: Arc::new(Mutex::new(circuit)),
+        }
+    }
+
+    /// Execute a fallible operation with circuit breaker protection.
+    ///
+    /// Accepts the same inputs as [`CircuitBreaker::call`]. Neither the
+    /// operation nor the fallback runs under the internal lock.
+    pub fn call<I, T, E: 'static>(&self, input: I) -> Result<T, CircuitError<E>>
+    where
+        I: IntoCallOptions<T, E>,
+    {
+        let (f, options) = input.into_call_options();
+
+        let (result, context) = if self.fast.enabled() {
+            (self.call_fast(f), None)
+        } else {
+            self.call_locked(f)
+        };
+
+        options.finish(result, context, |reason| {
+            self.lock_inner().fallback_context(reason)
+        })
+    }
+
+    /// Execute a fallible operation, giving up after `call_timeout_secs`.
+    ///
+    /// See [`CircuitBreaker::call_with_timeout`]; the operation runs on a
+    /// worker thread and the caller waits without holding the internal lock.
+    pub fn call_with_timeout<F, T, E>(&self, f: F) -> Result<T, CircuitError<E>>
+    where
+        F: FnOnce() -> Result<T, E> + Send + 'static,
+        T: Send + 'static,
+        E: Send + 'static,
+    {
+        self.call_with_timeout_and_options(f, CallOptions::new())
+    }
+
+    /// [`call_with_timeout`](Self::call_with_timeout) with fallbacks.
+    ///
+    /// See [`CircuitBreaker::call_with_timeout_and_options`]. The fallbacks
+    /// run without the internal lock.
+    pub fn call_with_timeout_and_options<F, T, E>(
+        &self,
+        f: F,
+        options: CallOptions<T, E>,
+    ) -> Result<T, CircuitError<E>>
+    where
+        F: FnOnce() -> Result<T, E> + Send + 'static,
+        T: Send + 'static,
+        E: Send + 'static,
+    {
+        let (result, context) = self.call_timed_locked(f);
+
+        options.finish(result, context, |reason| {
+            self.lock_inner().fallback_context(reason)
+        })
+    }
+
+    /// Run a call through the circuit lock; an Open circuit hands back the
+    /// context for its fallbacks
+    fn call_locked<T, E: 'static>(
+        &self,
+        f: Box<dyn FnOnce() -> Result<T, E>>,
+    ) -> (Result<T, CircuitError<E>>, Option<FallbackContext>) {
+        let (gate, start) = {
+            let mut circuit = self.lock_inner();
+            match circuit.prepare_call() {
+                Ok(gate) => (gate, circuit.start_time()),
+                Err(error) => return (Err(error), None),
+            }
+        };
+
+        match gate {
+            CallGate::Execute(permit) => {
+                let half_open_probe = permit.half_open_probe();
+                let mut probe = HalfOpenProbe {
+                    circuit: self,
+                    active: half_open_probe,
+                };
+                let result = f();
+                probe.disarm();
+
+                let output = self
+                    .lock_inner()
+                    .complete_call(start, result, half_open_probe);
+                drop(permit);
+                (output, None)
+            }
+            CallGate::Open {
+                _permit: permit,
+                context,
+            } => {
+                drop(permit);
+                (Err(context.clone().into_open_error()), Some(context))
+            }
+        }
+    }
+
+    /// Like [`call_locked`](Self::call_locked), but the operation runs on a
+    /// worker thread when the circuit has a call timeout
+    fn call_timed_locked<F, T, E>(
+        &self,
+        f: F,
+    ) -> (Result<T, CircuitError<E>>, Option<FallbackContext>)
+    where
+        F: FnOnce() -> Result<T, E> + Send + 'static,
+        T: Send + 'static,
+        E: Send + 'static,
+    {
+        let (gate, start, timeout_secs, max_abandoned, abandoned) = {
+            let mut circuit = self.lock_inner();
+            match circuit.prepare_call() {
+                Ok(gate) => (
+                    gate,
+                    circuit.start_time(),
+                    circuit.config().call_timeout_secs,
+                    circuit.config().max_abandoned_calls,
+                    circuit.abandoned_workers().clone(),
+                ),
+                Err(error) => return (Err(error), None),
+            }
+        };
+
+        match gate {
+            CallGate::Execute(permit) => {
+                let half_open_probe = permit.half_open_probe();
+                let mut probe = HalfOpenProbe {
+                    circuit: self,
+                    active: half_open_probe,
+                };
+                let result = match timeout_secs {
+                    Some(timeout_secs) => {
+                        run_on_worker(timeout_secs, &abandoned, max_abandoned, permit, f)
+                    }
+                    None => {
+                        let result = f();
+                        drop(permit);
+                        Ok(result)
+                    }
+                };
+                probe.disarm();
+
+                let output = self
+                    .lock_inner()
+                    .complete_timed_call(start, result, half_open_probe);
+                (output, None)
+            }
+            CallGate::Open {
+                _permit: permit,
+                context,
+            } => {
+                drop(permit);
+                (Err(context.clone().into_open_error()), Some(context))
+            }
+        }
+    }
+
+    /// Run a call admitted by the fast path; only failures and slow calls lock
+    fn call_fast<T, E: 'static>(
+        &self,
+        f: Box<dyn FnOnce() -> Result<T, E>>,
+    ) -> Result<T, CircuitError<E>> {
+        let start = self.fast.start_time();
+        let result = f();
+
+        if result.is_ok()
+            && let Some(recorded) = self.fast.record_success(start)
+        {
+            if let Err(error) = recorded {
+                self.lock_inner().track_storage(Err::<(), _>(error));
+            }
+            return result.map_err(CircuitError::Execution);
+        }
+
+        self.lock_inner().complete_call(start, result, false)
+    }
+
+    /// Record a successful operation and drive HalfOpen -> Closed transitions.
+    pub fn record_success_and_maybe_close(&self, duration: f64) {
+        self.lock_inner().record_success_and_maybe_close(duration);
+    }
+
+    /// Record a failed operation and attempt to trip the circuit.
+    pub fn record_failure_and_maybe_trip(&self, duration: f64) {
+        self.lock_inner().record_failure_and_maybe_trip(duration);
+    }
+
+    /// Record a successful operation.
+    pub fn record_success(&self, duration: f64) {
+        self.lock_inner().record_success(duration);
+    }
+
+    /// Record a failed operation.
+    pub fn record_failure(&self, duration: f64) {
+        self.lock_inner().record_failure(duration);
+    }
+
+    /// Check failure threshold and attempt to trip the circuit.
+    pub fn check_and_trip(&self) -> bool {
+        self.lock_inner().check_and_trip()
+    }
+
+    /// Name the circuit was created with.
+    pub fn name(&self) -> String {
+        String::from(self.lock_inner().name())
+    }
+
+    /// Check if circuit is open.
+    pub fn is_open(&self) -> bool {
+        self.lock_inner().is_open()
+    }
+
+    /// Seconds until an Open circuit lets a probe through, or None if it isn't Open.
+    pub fn retry_after(&self) -> Option<f64> {
+        self.lock_inner().retry_after()
+    }
+
+    /// Check if circuit is closed.
+    pub fn is_closed(&self) -> bool {
+        self.lock_inner().is_closed()
+    }
+
+    /// Get current state name, or the name of the override if one is set.
+    pub fn state_name(&self) -> &'static str {
+        self.lock_inner().state_name()
+    }
+
+    /// Snapshot of the state and the counts within the sliding window.
+    pub fn stats(&self) -> CircuitStats {
+        self.lock_inner().stats()
+    }
+
+    /// Last error reported by the storage backend, if it hasn't recovered since.
+    pub fn storage_error(&self) -> Option<StorageError> {
+        self.lock_inner().storage_error().cloned()
+    }
+
+    /// Operator override currently in effect.
+    pub fn override_mode(&self) -> Option<CircuitOverride> {
+        self.lock_inner().override_mode()
+    }
+
+    /// Handle for parent circuits to force this one open.
+    pub fn cascade_target(&self) -> CascadeTarget {
+        self.lock_inner().cascade_target()
+    }
+
+    /// Name of the parent circuit whose cascade holds this circuit open.
+    pub fn cascade_source(&self) -> Option<String> {
+        self.lock_inner().cascade_source()
+    }
+
+    /// Drop every parent's cascade latch.
+    pub fn clear_cascade(&self) {
+        self.lock_inner().clear_cascade();
+    }
+
+    /// Open the circuit and reject every call until the override is released.
+    pub fn force_open(&self) {
+        self.lock_inner().force_open();
+    }
+
+    /// Close the circuit and keep it closed, whatever the thresholds say.
+    pub fn force_close(&self) {
+        self.lock_inner().force_close();
+    }
+
+    /// Close the circuit and pass calls straight through, unrecorded.
+    pub fn disable(&self) {
+        self.lock_inner().disable();
+    }
+
+    /// Lift the override and let the thresholds drive the circuit again.
+    pub fn release_override(&self) {
+        self.lock_inner().release_override();
+    }
+
+    /// Clear all events, lift any override and reset circuit to Closed state.
+    pub fn reset(&self) {
+        self.lock_inner().reset();
+    }
+
+    /// Number of handles sharing this circuit
+    pub(crate) fn handle_count(&self) -> usize {
+        Arc::strong_count(&self.inner)
+    }
+
+    fn lock_inner(&self) -> CircuitGuard<'_> {
+        CircuitGuard::lock(&self.inner, &self.fast)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Barrier;
+
+    #[test]
+    fn shared_call_trips_for_every_handle() {
+        let circuit = CircuitBreaker::builder("test")
+            .failure_threshold(2)
+            .build_shared();
+        let other = circuit.clone();
+
+        let _ = circuit.call(|| Err::<(), _>("error 1"));
+        let _ = other.call(|| Err::<(), _>("error 2"));
+
+        assert!(circuit.is_open());
+        assert!(matches!(
+            other.call(|| Ok::<_, &str>("rejected")),
+            Err(CircuitError::Open { .. })
+        ));
+    }
+
+    #[test]
+    fn fast_path_failures_reach_the_fallbacks() {
+        let circuit = CircuitBreaker::builder("test").build_shared();
+        assert!(circuit.fast.enabled());
+
+        let result = circuit.call((
+            || Err::<&str, _>("boom"),
+            crate::CallOptions::new()
+                .with_fallback(|_ctx| Ok("fallback"))
+                .fallback_on(crate::FallbackReason::Execution),
+        ));
+        assert_eq!(result.unwrap(), "fallback");
+        assert_eq!(circuit.stats().failure_count, 1);
+    }
+
+    #[test]
+    fn fast_path_follows_the_state() {
+        let circuit = CircuitBreaker::builder("test")
+            .failure_threshold(2)
+            .half_open_timeout_secs(0.0)
+            .success_threshold(1)
+            .build_shared();
+        assert!(circuit.fast.enabled());
+
+        assert!(circuit.call(|| Ok::<_, &str>("fast")).is_ok());
+        assert_eq!(circuit.stats().success_count, 1);
+
+        // Failures go through the state machine and trip as usual
+        let _ = circuit.call(|| Err::<(), _>("error 1"));
+        assert!(circuit.fast.enabled());
+        let _ = circuit.call(|| Err::<(), _>("error 2"));
+        assert!(circuit.is_open());
+        assert!(!circuit.fast.enabled());
+
+        // The recovery probe closes the circuit and re-enables the fast path
+        assert!(circuit.call(|| Ok::<_, &str>("probe")).is_ok());
+        assert!(circuit.is_closed());
+        assert!(circuit.fast.enabled());
+
+        circuit.force_close();
+        assert!(!circuit.fast.enabled());
+        circuit.release_override();
+        assert!(circuit.fast.enabled());
+    }
+
+    #[test]
+    fn fast_path_needs_a_plain_closed_circuit() {
+        let bulkheaded = CircuitBreaker::builder("test")
+            .max_concurrency(1)
+            .build_shared();
+        assert!(!bulkheaded.fast.enabled());
+
+        let slow = CircuitBreaker::builder("test")
+            .disable_failure_threshold()
+            .slow_call_duration_secs(0.0)
+            .slow_call_rate(1.0)
+            .minimum_calls(1)
+            .build_shared();
+        assert!(slow.fast.enabled());
+        // A slow success may trip the circuit, so it takes the lock
+        assert!(slow.call(|| Ok::<_, &str>("slow")).is_ok());
+        assert!(slow.is_open());
+    }
+
+    #[test]
+    fn operations_run_outside_the_lock() {
+        let circuit = CircuitBreaker::builder("test").build_shared();
+        let barrier = Arc::new(Barrier::new(2));
+
+        // Both calls must be inside their operations at once to pass the barrier
+        let workers: std::vec::Vec<_> = (0..2)
+            .map(|_| {
+                let circuit = circuit.clone();
+                let barrier = Arc::clone(&barrier);
+                std::thread::spawn(move || {
+                    circuit.call(move || {
+                        barrier.wait();
+                        Ok::<_, &str>(())
+                    })
+                })
+            })
+            .collect();
+
+        for worker in workers {
+            assert!(worker.join().unwrap().is_ok());
+        }
+        assert_eq!(circuit.stats().success_count, 2);
+    }
+
+    #[test]
+    fn panicking_probe_releases_its_slot() {
+        let circuit = CircuitBreaker::builder("test")
+            .failure_threshold(1)
+            .half_open_timeout_secs(0.0)
+            .success_threshold(1)
+            .build_shared();
+        let _ = circuit.call(|| Err::<(), _>("trip"));
+
+        let panicking = circuit.clone();
+        let result = std::thread::spawn(move || {
+            panicking.call(|| -> Result<(), &str> { panic!("probe panicked") })
+        })
+        .join();
+        assert!(result.is_err());
+
+        assert_eq!(circuit.state_name(), "HalfOpen");
+        assert!(circuit.call(|| Ok::<_, &str>("probe")).is_ok());
+        assert!(circuit.is_closed());
+    }
+
+    #[test]
+    fn timed_out_probe_reopens_the_circuit() {
+        let circuit = CircuitBreaker::builder("test")
+            .failure_threshold(1)
+            .half_open_timeout_secs(0.0)
+            .success_threshold(1)
+            .call_timeout_secs(0.01)
+            .build_shared();
+        let _ = circuit.call(|| Err::<(), _>("trip"));
+
+        let result = circuit.call_with_timeout(|| {
+            std::thread::sleep(std::time::Duration::from_millis(200));
+            Ok::<_, &str>("late")
+        });
+        assert!(matches!(result, Err(CircuitError::Timeout { .. })));
+        assert!(circuit.is_open());
+
+        // The timed-out probe gave its slot back
+        let result = circuit.call_with_timeout(|| Ok::<_, &str>("probe"));
+        assert_eq!(result.unwrap(), "probe");
+        assert!(circuit.is_closed());
+    }
+
+    #[test]
+    fn abandoned_workers_are_bounded_across_clones() {
+        let circuit = CircuitBreaker::builder("test")
+            .failure_threshold(10)
+            .call_timeout_secs(0.01)
+            .max_abandoned_calls(1)
+            .build_shared();
+        let (release, hung) = std::sync::mpsc::channel::<()>();
+
+        let result = circuit.call_with_timeout(move || {
+            let _ = hung.recv();
+            Ok::<_, &str>("late")
+        });
+        assert!(matches!(result, Err(CircuitError::Timeout { .. })));
+
+        let clone = circuit.clone();
+        let result = std::thread::spawn(move || {
+            clone.call_with_timeout_and_options(
+                || Ok::<_, &str>("refused"),
+                CallOptions::new()
+                    .with_fallback(|ctx| {
+                        assert_eq!(ctx.reason, crate::FallbackReason::TimeoutWorkersExhausted);
+                        Ok("fallback")
+                    })
+                    .fallback_on(crate::FallbackReason::TimeoutWorkersExhausted),
+            )
+        })
+        .join()
+        .unwrap();
+        assert_eq!(result.unwrap(), "fallback");
+
+        release.send(()).unwrap();
+        let recovered = (0..1000).find_map(|_| {
+            std::thread::sleep(std::time::Duration::from_millis(1));
+            circuit.call_with_timeout(|| Ok::<_, &str>("ok")).ok()
+        });
+        assert_eq!(recovered, Some("ok"));
+    }
+}
@@ -2,8 +2,34 @@
 //!
 //! This module provides different storage implementations:
 //! - `MemoryStorage`: Thread-safe in-memory storage with sliding window
+//! - `BucketedStorage`: Constant-time windowed counters in fixed-size time buckets
+//! - `SharedFileStorage`: Memory-mapped counters and state shared across processes
+//!   (`shared-file` feature, unix only)
+//! - `FallbackChainStorage`: Tries several backends in order, skipping unhealthy ones
+//! - `RespStorage`: Counters and state on a Redis-protocol server (`resp` feature)
 //! - `NullStorage`: No-op storage for testing and benchmarking
 
+#[cfg(feature = "async")]
+mod async_backend;
+mod bucketed;
+mod fallback_chain;
+#[cfg(feature = "resp")]
+mod resp;
+mod shard;
+#[cfg(all(feature = "shared-file", unix))]
+mod shared_file;
+
+#[cfg(feature = "async")]
+pub use async_backend::{AsyncStorageBackend, StorageFuture};
+pub use bucketed::BucketedStorage;
+pub use fallback_chain::FallbackChainStorage;
+#[cfg(feature = "resp")]
+pub use resp::RespStorage;
+#[cfg(all(feature = "shared-file", unix))]
+pub use shared_file::SharedFileStorage;
+
+use crate::circuit::CircuitState;
+use crate::errors::StorageError;
 use crate::time::Clock;
 #[cfg(feature = "std")]
 use crate::time::SystemClock;
@@ -14,7 +40,8 @@ use alloc::boxed::Box;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use hashbrown::HashMap;
-use spin::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use shard::ShardedMap;
+use spin::RwLock;
 #[cfg(feature = "std")]
 use std::time::Instant;
 
@@ -54,13 +81,281 @@ pub trait StorageBackend: Send + Sync + core::fmt::Debug {
 
     /// Get monotonic time in seconds (relative to storage creation)
     fn monotonic_time(&self) -> f64;
+
+    /// Load the persisted state of a circuit
+    ///
+    /// Returns `None` when nothing was stored, in which case the circuit
+    /// starts Closed. Backends that don't persist state keep the default.
+    fn get_status(&self, _circuit_name: &str) -> Option<CircuitStatus> {
+        None
+    }
+
+    /// Persist the state of a circuit after a transition
+    ///
+    /// `opened_at` is a [`monotonic_time`](Self::monotonic_time) reading, so a
+    /// backend that outlives the process must keep its clock origin stable for
+    /// restored Open circuits to time out correctly.
+    fn set_status(&self, _circuit_name: &str, _status: CircuitStatus) {}
+
+    /// Record that an operation (already recorded as a success or failure)
+    /// was slow
+    ///
+    /// The circuit decides what counts as slow from its configuration, so
+    /// backends only count these calls. Backends that don't track latency
+    /// keep the default, and slow-call thresholds never trip with them.
+    fn record_slow_call(&self, _circuit_name: &str, _duration: f64) {}
+
+    /// Count slow operations within a time window
+    fn slow_call_count(&self, _circuit_name: &str, _window_seconds: f64) -> usize {
+        0
+    }
+
+    /// Tally the outcomes of a circuit's last `calls` recorded operations,
+    /// regardless of age
+    ///
+    /// Backs count-based sliding windows. Calls taking at least
+    /// `slow_call_secs` count as slow. The default tallies
+    /// [`event_log`](Self::event_log), so backends without an event log
+    /// should override it.
+    fn recent_calls(
+        &self,
+        circuit_name: &str,
+        calls: usize,
+        slow_call_secs: Option<f64>,
+    ) -> CallCounts {
+        CallCounts::tally(&self.event_log(circuit_name, calls), slow_call_secs)
+    }
+
+    /// Whether other processes can change the persisted status
+    ///
+    /// When `true`, circuits re-read their status before each call so an
+    /// outage seen by one process opens the circuit everywhere.
+    fn shares_state(&self) -> bool {
+        false
+    }
+
+    // Fallible variants
+    //
+    // The methods above swallow backend failures. Backends that can fail
+    // override these to report them; the defaults delegate to the infallible
+    // methods and always succeed.
+
+    /// Record a successful operation, reporting backend failures
+    fn try_record_success(&self, circuit_name: &str, duration: f64) -> Result<(), StorageError> {
+        self.record_success(circuit_name, duration);
+        Ok(())
+    }
+
+    /// Record a failed operation, reporting backend failures
+    fn try_record_failure(&self, circuit_name: &str, duration: f64) -> Result<(), StorageError> {
+        self.record_failure(circuit_name, duration);
+        Ok(())
+    }
+
+    /// Count successful operations, reporting backend failures
+    fn try_success_count(
+        &self,
+        circuit_name: &str,
+        window_seconds: f64,
+    ) -> Result<usize, StorageError> {
+        Ok(self.success_count(circuit_name, window_seconds))
+    }
+
+    /// Count failed operations, reporting backend failures
+    fn try_failure_count(
+        &self,
+        circuit_name: &str,
+        window_seconds: f64,
+    ) -> Result<usize, StorageError> {
+        Ok(self.failure_count(circuit_name, window_seconds))
+    }
+
+    /// Record a slow operation, reporting backend failures
+    fn try_record_slow_call(&self, circuit_name: &str, duration: f64) -> Result<(), StorageError> {
+        self.record_slow_call(circuit_name, duration);
+        Ok(())
+    }
+
+    /// Count slow operations, reporting backend failures
+    fn try_slow_call_count(
+        &self,
+        circuit_name: &str,
+        window_seconds: f64,
+    ) -> Result<usize, StorageError> {
+        Ok(self.slow_call_count(circuit_name, window_seconds))
+    }
+
+    /// Tally recent operations, reporting backend failures
+    fn try_recent_calls(
+        &self,
+        circuit_name: &str,
+        calls: usize,
+        slow_call_secs: Option<f64>,
+    ) -> Result<CallCounts, StorageError> {
+        Ok(self.recent_calls(circuit_name, calls, slow_call_secs))
+    }
+
+    /// Clear all events for a circuit, reporting backend failures
+    fn try_clear(&self, circuit_name: &str) -> Result<(), StorageError> {
+        self.clear(circuit_name);
+        Ok(())
+    }
+
+    /// Clear all circuits, reporting backend failures
+    fn try_clear_all(&self) -> Result<(), StorageError> {
+        self.clear_all();
+        Ok(())
+    }
+
+    /// Load the persisted state of a circuit, reporting backend failures
+    fn try_get_status(&self, circuit_name: &str) -> Result<Option<CircuitStatus>, StorageError> {
+        Ok(self.get_status(circuit_name))
+    }
+
+    /// Persist the state of a circuit, reporting backend failures
+    fn try_set_status(
+        &self,
+        circuit_name: &str,
+        status: CircuitStatus,
+    ) -> Result<(), StorageError> {
+        self.set_status(circuit_name, status);
+        Ok(())
+    }
+}
+
+/// Outcome counts over a window of calls
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CallCounts {
+    pub successes: usize,
+    pub failures: usize,
+    /// Calls (successful or not) at or above the slow-call duration
+    pub slow_calls: usize,
+}
+
+impl CallCounts {
+    /// Total number of calls counted
+    pub fn total(&self) -> usize {
+        self.successes + self.failures
+    }
+
+    /// Count one call
+    pub fn add(&mut self, kind: EventKind, duration: f64, slow_call_secs: Option<f64>) {
+        match kind {
+            EventKind::Success => self.successes += 1,
+            EventKind::Failure => self.failures += 1,
+        }
+        if slow_call_secs.is_some_and(|threshold| duration >= threshold) {
+            self.slow_calls += 1;
+        }
+    }
+
+    pub(crate) fn tally<'a>(
+        events: impl IntoIterator<Item = &'a Event>,
+        slow_call_secs: Option<f64>,
+    ) -> Self {
+        let mut counts = Self::default();
+        for event in events {
+            counts.add(event.kind, event.duration, slow_call_secs);
+        }
+        counts
+    }
+}
+
+/// Persisted circuit state, as stored by [`StorageBackend::set_status`]
+///
+/// Build one with [`CircuitStatus::new`] or [`CircuitStatus::open`]; more
+/// fields may be added in later releases.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct CircuitStatus {
+    /// State the circuit was in when it was persisted
+    pub state: CircuitState,
+    /// Storage monotonic time when the circuit opened (Open state only)
+    pub opened_at: Option<f64>,
+    /// Consecutive Open periods without a successful close (Open and HalfOpen
+    /// states), or 0 if unknown; drives the backed-off Open timeout
+    pub attempt: u32,
+    /// Open timeout of the current period, after backoff and jitter (Open
+    /// state only)
+    pub timeout_secs: Option<f64>,
+}
+
+impl CircuitStatus {
+    /// Status for a circuit that is not open
+    pub fn new(state: CircuitState) -> Self {
+        Self {
+            state,
+            opened_at: None,
+            attempt: 0,
+            timeout_secs: None,
+        }
+    }
+
+    /// Status for an Open circuit
+    pub fn open(opened_at: f64) -> Self {
+        Self {
+            opened_at: Some(opened_at),
+            ..Self::new(CircuitState::Open)
+        }
+    }
+
+    /// Set the consecutive Open attempt
+    pub fn with_attempt(mut self, attempt: u32) -> Self {
+        self.attempt = attempt;
+        self
+    }
+
+    /// Set the Open timeout drawn for this period
+    pub fn with_timeout_secs(mut self, timeout_secs: f64) -> Self {
+        self.timeout_secs = Some(timeout_secs);
+        self
+    }
+}
+
+/// Circuit status table shared by the in-memory backends
+///
+/// State changes are rare compared to event recording, so a single lock is
+/// enough here.
+#[derive(Debug, Default)]
+struct StatusTable(RwLock<HashMap<String, CircuitStatus>>);
+
+impl StatusTable {
+    fn get(&self, circuit_name: &str) -> Option<CircuitStatus> {
+        self.0.read().get(circuit_name).copied()
+    }
+
+    fn set(&self, circuit_name: &str, status: CircuitStatus) {
+        let mut statuses = self.0.write();
+        match statuses.get_mut(circuit_name) {
+            Some(current) => *current = status,
+            None => {
+                statuses.insert(circuit_name.to_string(), status);
+            }
+        }
+    }
+
+    fn remove(&self, circuit_name: &str) {
+        self.0.write().remove(circuit_name);
+    }
+
+    fn clear(&self) {
+        self.0.write().clear();
+    }
 }
 
 /// Thread-safe in-memory storage for circuit breaker events
+///
+/// Each circuit's events sit behind their own lock, and circuits are spread
+/// over independently locked shards, so recording into one circuit never
+/// waits on another.
 #[derive(Debug)]
 pub struct MemoryStorage {
     /// Events keyed by circuit name
-    events: RwLock<HashMap<String, Vec<Event>>>,
+    events: ShardedMap<RwLock<Vec<Event>>>,
+    /// Timestamps of slow calls keyed by circuit name
+    slow_calls: ShardedMap<RwLock<Vec<f64>>>,
+    /// Persisted circuit states
+    statuses: StatusTable,
     /// Maximum events to keep per circuit
     max_events: usize,
     /// Monotonic time source
@@ -86,7 +381,9 @@ impl MemoryStorage {
     /// Create storage with both a custom event cap and time source.
     pub fn with_max_events_and_clock(max_events: usize, clock: Box<dyn Clock>) -> Self {
         Self {
-            events: RwLock::new(HashMap::new()),
+            events: ShardedMap::new(),
+            slow_calls: ShardedMap::new(),
+            statuses: StatusTable::default(),
             max_events,
             clock,
         }
@@ -94,45 +391,40 @@ impl MemoryStorage {
 
     // Private helper methods
 
-    fn events_read(&self) -> RwLockReadGuard<'_, HashMap<String, Vec<Event>>> {
-        self.events.read()
-    }
-
-    fn events_write(&self) -> RwLockWriteGuard<'_, HashMap<String, Vec<Event>>> {
-        self.events.write()
-    }
-
     fn record_event(&self, circuit_name: &str, kind: EventKind, duration: f64) {
-        let mut events = self.events_write();
-        let circuit_events = events.entry(circuit_name.to_string()).or_default();
+        let slot = self
+            .events
+            .get_or_insert_with(circuit_name, || RwLock::new(Vec::new()));
+        let mut circuit_events = slot.write();
 
         circuit_events.push(Event {
             kind,
             timestamp: self.monotonic_time(),
             duration,
         });
+        self.trim(&mut circuit_events);
+    }
 
-        // Cleanup old events if we exceed max_events
-        if circuit_events.len() > self.max_events {
+    /// Cleanup old entries if we exceed max_events
+    fn trim<T>(&self, entries: &mut Vec<T>) {
+        if entries.len() > self.max_events {
             // Remove oldest 10% to avoid cleanup on every event
             // Ensure we remove at least 1 event even with small max_events
             let remove_count = (self.max_events / 10).max(1);
-            circuit_events.drain(0..remove_count);
+            entries.drain(0..remove_count);
         }
     }
 
     fn count_events(&self, circuit_name: &str, kind: EventKind, window_seconds: f64) -> usize {
-        let events = self.events_read();
+        let Some(slot) = self.events.get(circuit_name) else {
+            return 0;
+        };
         let cutoff = self.monotonic_time() - window_seconds;
 
-        events
-            .get(circuit_name)
-            .map(|ev| {
-                ev.iter()
-                    .filter(|e| e.kind == kind && e.timestamp >= cutoff)
-                    .count()
-            })
-            .unwrap_or(0)
+        slot.read()
+            .iter()
+            .filter(|e| e.kind == kind && e.timestamp >= cutoff)
+            .count()
     }
 }
 
@@ -159,21 +451,52 @@ impl StorageBackend for MemoryStorage {
         self.count_events(circuit_name, EventKind::Failure, window_seconds)
     }
 
+    fn record_slow_call(&self, circuit_name: &str, _duration: f64) {
+        let slot = self
+            .slow_calls
+            .get_or_insert_with(circuit_name, || RwLock::new(Vec::new()));
+        let mut timestamps = slot.write();
+        timestamps.push(self.monotonic_time());
+        self.trim(&mut timestamps);
+    }
+
+    fn slow_call_count(&self, circuit_name: &str, window_seconds: f64) -> usize {
+        let Some(slot) = self.slow_calls.get(circuit_name) else {
+            return 0;
+        };
+        let cutoff = self.monotonic_time() - window_seconds;
+        slot.read().iter().filter(|&&t| t >= cutoff).count()
+    }
+
+    fn recent_calls(
+        &self,
+        circuit_name: &str,
+        calls: usize,
+        slow_call_secs: Option<f64>,
+    ) -> CallCounts {
+        let Some(slot) = self.events.get(circuit_name) else {
+            return CallCounts::default();
+        };
+        CallCounts::tally(slot.read().iter().rev().take(calls), slow_call_secs)
+    }
+
     fn clear(&self, circuit_name: &str) {
-        let mut events = self.events_write();
-        events.remove(circuit_name);
+        self.events.remove(circuit_name);
+        self.slow_calls.remove(circuit_name);
+        self.statuses.remove(circuit_name);
     }
 
     fn clear_all(&self) {
-        let mut events = self.events_write();
-        events.clear();
+        self.events.clear();
+        self.slow_calls.clear();
+        self.statuses.clear();
     }
 
     fn event_log(&self, circuit_name: &str, limit: usize) -> Vec<Event> {
-        let events = self.events_read();
-        events
+        self.events
             .get(circuit_name)
-            .map(|ev| {
+            .map(|slot| {
+                let ev = slot.read();
                 let start = if ev.len() > limit {
                     ev.len() - limit
                 } else {
@@ -187,6 +510,14 @@ impl StorageBackend for MemoryStorage {
     fn monotonic_time(&self) -> f64 {
         self.clock.now_secs()
     }
+
+    fn get_status(&self, circuit_name: &str) -> Option<CircuitStatus> {
+        self.statuses.get(circuit_name)
+    }
+
+    fn set_status(&self, circuit_name: &str, status: CircuitStatus) {
+        self.statuses.set(circuit_name, status);
+    }
 }
 
 /// No-op storage backend for testing and benchmarking
@@ -298,6 +629,41 @@ mod tests {
         assert_eq!(storage.success_count("test_circuit", 60.0), 0);
     }
 
+    #[test]
+    fn test_memory_storage_slow_calls() {
+        let storage = MemoryStorage::new();
+
+        storage.record_success("test_circuit", 5.0);
+        storage.record_slow_call("test_circuit", 5.0);
+        assert_eq!(storage.slow_call_count("test_circuit", 60.0), 1);
+        assert_eq!(storage.slow_call_count("other_circuit", 60.0), 0);
+        // Slow calls are counted alongside, not instead of, the outcome
+        assert_eq!(storage.success_count("test_circuit", 60.0), 1);
+
+        storage.clear("test_circuit");
+        assert_eq!(storage.slow_call_count("test_circuit", 60.0), 0);
+    }
+
+    #[test]
+    fn test_memory_storage_recent_calls() {
+        let storage = MemoryStorage::new();
+
+        storage.record_failure("test_circuit", 0.1);
+        storage.record_success("test_circuit", 2.0);
+        storage.record_failure("test_circuit", 3.0);
+        storage.record_success("test_circuit", 0.2);
+
+        let counts = storage.recent_calls("test_circuit", 3, Some(1.0));
+        assert_eq!(counts.successes, 2);
+        assert_eq!(counts.failures, 1);
+        assert_eq!(counts.slow_calls, 2);
+        assert_eq!(storage.recent_calls("test_circuit", 100, None).total(), 4);
+        assert_eq!(
+            storage.recent_calls("other_circuit", 10, None),
+            CallCounts::default()
+        );
+    }
+
     #[test]
     fn test_memory_storage_event_log() {
         let storage = MemoryStorage::new();
@@ -321,8 +687,8 @@ mod tests {
             storage.record_success("test_circuit", i as f64 * 0.01);
         }
 
-        let events = storage.events.read();
-        let circuit_events = events.get("test_circuit").unwrap();
+        let slot = storage.events.get("test_circuit").unwrap();
+        let circuit_events = slot.read();
 
         assert!(circuit_events.len() <= 100);
     }
@@ -335,8 +701,8 @@ mod tests {
             storage.record_success("test_circuit", i as f64 * 0.01);
         }
 
-        let events = storage.events.read();
-        let circuit_events = events.get("test_circuit").unwrap();
+        let slot = storage.events.get("test_circuit").unwrap();
+        let circuit_events = slot.read();
 
         assert!(
             circuit_events.len() <= 5,
@@ -361,6 +727,53 @@ mod tests {
         assert_eq!(storage.success_count("test_circuit", 1.0), 2);
     }
 
+    #[test]
+    fn test_memory_storage_concurrent_circuits() {
+        use std::sync::Arc;
+
+        let storage = Arc::new(MemoryStorage::new());
+        let handles: Vec<_> = (0..8)
+            .map(|t| {
+                let storage = Arc::clone(&storage);
+                std::thread::spawn(move || {
+                    let name = std::format!("circuit_{t}");
+                    for _ in 0..500 {
+                        storage.record_failure(&name, 0.01);
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        for t in 0..8 {
+            let name = std::format!("circuit_{t}");
+            assert_eq!(storage.failure_count(&name, 60.0), 500);
+        }
+    }
+
+    #[test]
+    fn test_memory_storage_status_round_trip() {
+        let storage = MemoryStorage::new();
+        assert_eq!(storage.get_status("test_circuit"), None);
+
+        storage.set_status("test_circuit", CircuitStatus::open(12.5));
+        assert_eq!(
+            storage.get_status("test_circuit"),
+            Some(CircuitStatus::open(12.5))
+        );
+
+        storage.set_status("test_circuit", CircuitStatus::new(CircuitState::Closed));
+        assert_eq!(
+            storage.get_status("test_circuit").map(|s| s.state),
+            Some(CircuitState::Closed)
+        );
+
+        storage.clear("test_circuit");
+        assert_eq!(storage.get_status("test_circuit"), None);
+    }
+
     #[test]
     fn test_null_storage_discards_events() {
         let storage = NullStorage::new();
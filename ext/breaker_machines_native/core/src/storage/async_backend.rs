@@ -0,0 +1,143 @@
+//! Async storage backend trait
+//!
+//! Backends that do network I/O implement [`AsyncStorageBackend`] so an
+//! [`AsyncCircuitBreaker`](crate::AsyncCircuitBreaker) can await them instead
+//! of blocking the executor. Methods return boxed futures so the trait stays
+//! object-safe and can be shared as `Arc<dyn AsyncStorageBackend>`.
+
+use super::{CallCounts, CircuitStatus};
+use crate::errors::StorageError;
+use std::boxed::Box;
+use std::future::Future;
+use std::pin::Pin;
+
+/// Future returned by [`AsyncStorageBackend`] methods
+pub type StorageFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T, StorageError>> + Send + 'a>>;
+
+/// Storage backend whose operations are futures
+///
+/// The async counterpart of [`StorageBackend`](crate::StorageBackend): every
+/// operation that may touch the network is async and fallible. Only the clock
+/// is synchronous, since circuits read it under their internal lock.
+///
+/// # Example
+///
+/// ```rust
+/// use breaker_machines::{AsyncStorageBackend, MemoryStorage, StorageBackend, StorageFuture};
+///
+/// /// Serves counts from memory, standing in for an async client
+/// #[derive(Debug, Default)]
+/// struct InMemory(MemoryStorage);
+///
+/// impl AsyncStorageBackend for InMemory {
+///     fn record_success<'a>(&'a self, name: &'a str, duration: f64) -> StorageFuture<'a, ()> {
+///         Box::pin(async move { self.0.try_record_success(name, duration) })
+///     }
+///
+///     fn record_failure<'a>(&'a self, name: &'a str, duration: f64) -> StorageFuture<'a, ()> {
+///         Box::pin(async move { self.0.try_record_failure(name, duration) })
+///     }
+///
+///     fn success_count<'a>(&'a self, name: &'a str, window: f64) -> StorageFuture<'a, usize> {
+///         Box::pin(async move { self.0.try_success_count(name, window) })
+///     }
+///
+///     fn failure_count<'a>(&'a self, name: &'a str, window: f64) -> StorageFuture<'a, usize> {
+///         Box::pin(async move { self.0.try_failure_count(name, window) })
+///     }
+///
+///     fn clear<'a>(&'a self, name: &'a str) -> StorageFuture<'a, ()> {
+///         Box::pin(async move { self.0.try_clear(name) })
+///     }
+///
+///     fn monotonic_time(&self) -> f64 {
+///         self.0.monotonic_time()
+///     }
+/// }
+/// ```
+pub trait AsyncStorageBackend: Send + Sync + core::fmt::Debug {
+    /// Record a successful operation
+    fn record_success<'a>(&'a self, circuit_name: &'a str, duration: f64) -> StorageFuture<'a, ()>;
+
+    /// Record a failed operation
+    fn record_failure<'a>(&'a self, circuit_name: &'a str, duration: f64) -> StorageFuture<'a, ()>;
+
+    /// Count successful operations within a time window
+    fn success_count<'a>(
+        &'a self,
+        circuit_name: &'a str,
+        window_seconds: f64,
+    ) -> StorageFuture<'a, usize>;
+
+    /// Count failed operations within a time window
+    fn failure_count<'a>(
+        &'a self,
+        circuit_name: &'a str,
+        window_seconds: f64,
+    ) -> StorageFuture<'a, usize>;
+
+    /// Record an operation that exceeded the slow-call duration threshold
+    fn record_slow_call<'a>(
+        &'a self,
+        _circuit_name: &'a str,
+        _duration: f64,
+    ) -> StorageFuture<'a, ()> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Count slow operations within a time window
+    fn slow_call_count<'a>(
+        &'a self,
+        _circuit_name: &'a str,
+        _window_seconds: f64,
+    ) -> StorageFuture<'a, usize> {
+        Box::pin(async { Ok(0) })
+    }
+
+    /// Tally the outcomes of a circuit's last `calls` operations
+    ///
+    /// Backs count-based sliding windows; backends that keep no per-call
+    /// history keep the default, which counts nothing.
+    fn recent_calls<'a>(
+        &'a self,
+        _circuit_name: &'a str,
+        _calls: usize,
+        _slow_call_secs: Option<f64>,
+    ) -> StorageFuture<'a, CallCounts> {
+        Box::pin(async { Ok(CallCounts::default()) })
+    }
+
+    /// Clear all events for a circuit
+    fn clear<'a>(&'a self, circuit_name: &'a str) -> StorageFuture<'a, ()>;
+
+    /// Get monotonic time in seconds
+    ///
+    /// Used for call durations and to time out Open circuits, so it must not
+    /// block.
+    fn monotonic_time(&self) -> f64;
+
+    /// Load the persisted state of a circuit
+    ///
+    /// Read once before a circuit's first call, and before every call when
+    /// [`shares_state`](Self::shares_state) is `true`.
+    fn get_status<'a>(
+        &'a self,
+        _circuit_name: &'a str,
+    ) -> StorageFuture<'a, Option<CircuitStatus>> {
+        Box::pin(async { Ok(None) })
+    }
+
+    /// Persist the state of a circuit after a transition
+    fn set_status<'a>(
+        &'a self,
+        _circuit_name: &'a str,
+        _status: CircuitStatus,
+    ) -> StorageFuture<'a, ()> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Whether other processes can change the persisted status
+    fn shares_state(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,697 @@
+//! Bucketed sliding-window storage
+//!
+//! Keeps fixed-size ring buckets of success/failure counters per circuit, so
+//! window counts cost O(window / bucket_secs) regardless of call volume. This
+//! mirrors the Ruby `Storage::BucketMemory` backend.
+
+use super::shard::ShardedMap;
+use super::{CallCounts, CircuitStatus, StatusTable, StorageBackend, default_clock};
+use crate::time::Clock;
+use crate::{Event, EventKind};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicBool, AtomicU64, Ordering, fence};
+
+/// Default number of buckets per circuit (5 minutes of 1-second buckets)
+const DEFAULT_BUCKET_COUNT: usize = 300;
+
+/// Default bucket width in seconds
+const DEFAULT_BUCKET_SECS: f64 = 1.0;
+
+/// Default size of the per-circuit event log
+const DEFAULT_MAX_EVENTS: usize = 100;
+
+/// A lock-free counter tagged with the time slice it belongs to.
+///
+/// The high 32 bits hold the slice's epoch tag and the low 32 bits the count,
+/// so resetting a stale bucket and incrementing it is a single CAS. A counter
+/// whose tag doesn't match the slice being read or written is treated as empty.
+///
+/// `repr(transparent)` so the shared-file backend can lay counters directly
+/// over mapped memory.
+#[derive(Debug, Default)]
+#[repr(transparent)]
+pub(super) struct SliceCounter(AtomicU64);
+
+impl SliceCounter {
+    fn tag(epoch: u64) -> u64 {
+        epoch & u64::from(u32::MAX)
+    }
+
+    pub(super) fn increment(&self, epoch: u64) {
+        let tag = Self::tag(epoch);
+        let mut current = self.0.load(Ordering::Acquire);
+        loop {
+            let next = if current >> 32 == tag {
+                current.saturating_add(1)
+            } else {
+                (tag << 32) | 1
+            };
+            match self
+                .0
+                .compare_exchange_weak(current, next, Ordering::AcqRel, Ordering::Acquire)
+            {
+                Ok(_) => return,
+                Err(actual) => current = actual,
+            }
+        }
+    }
+
+    pub(super) fn count(&self, epoch: u64) -> usize {
+        let value = self.0.load(Ordering::Acquire);
+        if value >> 32 == Self::tag(epoch) {
+            (value & u64::from(u32::MAX)) as usize
+        } else {
+            0
+        }
+    }
+
+    /// Reset the counter to empty regardless of its slice
+    #[cfg_attr(not(all(feature = "shared-file", unix)), allow(dead_code))]
+    pub(super) fn reset(&self) {
+        self.0.store(0, Ordering::Release);
+    }
+}
+
+/// Which counter of a bucket to touch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Counter {
+    Success,
+    Failure,
+    /// Calls at or above the circuit's slow-call threshold
+    Slow,
+}
+
+impl From<EventKind> for Counter {
+    fn from(kind: EventKind) -> Self {
+        match kind {
+            EventKind::Success => Counter::Success,
+            EventKind::Failure => Counter::Failure,
+        }
+    }
+}
+
+/// Success, failure and slow-call counters for one ring slot
+#[derive(Debug, Default)]
+#[repr(C)]
+pub(super) struct Bucket {
+    successes: SliceCounter,
+    failures: SliceCounter,
+    slow_calls: SliceCounter,
+}
+
+impl Bucket {
+    pub(super) fn counter(&self, counter: Counter) -> &SliceCounter {
+        match counter {
+            Counter::Success => &self.successes,
+            Counter::Failure => &self.failures,
+            Counter::Slow => &self.slow_calls,
+        }
+    }
+}
+
+/// One slot of an [`EventRing`], guarded by its own sequence number
+#[derive(Debug, Default)]
+struct EventSlot {
+    /// `2 * position + 1` while the event at `position` is being written,
+    /// `2 * position + 2` once it is published, 0 if never written
+    seq: AtomicU64,
+    timestamp: AtomicU64,
+    duration: AtomicU64,
+    failure: AtomicBool,
+}
+
+/// Fixed-size, lock-free log of a circuit's most recent events
+///
+/// Writers claim a position with one `fetch_add` and publish into its slot
+/// under the slot's sequence number; readers copy slots out and discard any
+/// that changed mid-read. Nothing ever blocks, at the cost of the log being
+/// best-effort: a writer that finds its slot still being written by a writer
+/// one full lap away drops its event, and events still being published are
+/// skipped by readers.
+#[derive(Debug)]
+struct EventRing {
+    slots: Box<[EventSlot]>,
+    /// Next position to claim
+    head: AtomicU64,
+}
+
+impl EventRing {
+    fn new(capacity: usize) -> Self {
+        Self {
+            slots: (0..capacity).map(|_| EventSlot::default()).collect(),
+            head: AtomicU64::new(0),
+        }
+    }
+
+    fn slot(&self, position: u64) -> &EventSlot {
+        &self.slots[(position % self.slots.len() as u64) as usize]
+    }
+
+    fn push(&self, event: Event) {
+        let position = self.head.fetch_add(1, Ordering::Relaxed);
+        let slot = self.slot(position);
+        let writing = 2 * position + 1;
+
+        let mut current = slot.seq.load(Ordering::Relaxed);
+        loop {
+            // Odd: another writer is mid-publish. Newer: the slot has moved on.
+            if current & 1 == 1 || current > writing {
+                return;
+            }
+            match slot.seq.compare_exchange_weak(
+                current,
+                writing,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(actual) => current = actual,
+            }
+        }
+
+        fence(Ordering::Release);
+        slot.timestamp
+            .store(event.timestamp.to_bits(), Ordering::Relaxed);
+        slot.duration
+            .store(event.duration.to_bits(), Ordering::Relaxed);
+        slot.failure
+            .store(event.kind == EventKind::Failure, Ordering::Relaxed);
+        slot.seq.store(writing + 1, Ordering::Release);
+    }
+
+    /// Copy out the event at `position`, unless it was overwritten or is
+    /// still being written
+    fn read(&self, position: u64) -> Option<Event> {
+        let slot = self.slot(position);
+        let published = 2 * position + 2;
+        if slot.seq.load(Ordering::Acquire) != published {
+            return None;
+        }
+
+        let event = Event {
+            kind: if slot.failure.load(Ordering::Relaxed) {
+                EventKind::Failure
+            } else {
+                EventKind::Success
+            },
+            timestamp: f64::from_bits(slot.timestamp.load(Ordering::Relaxed)),
+            duration: f64::from_bits(slot.duration.load(Ordering::Relaxed)),
+        };
+
+        fence(Ordering::Acquire);
+        (slot.seq.load(Ordering::Relaxed) == published).then_some(event)
+    }
+
+    /// Up to `limit` of the most recent events, oldest first
+    fn recent(&self, limit: usize) -> Vec<Event> {
+        let head = self.head.load(Ordering::Acquire);
+        let len = head.min(self.slots.len() as u64).min(limit as u64);
+        (head - len..head)
+            .filter_map(|position| self.read(position))
+            .collect()
+    }
+}
+
+/// Per-circuit ring of buckets plus the optional event log
+#[derive(Debug)]
+struct CircuitBuckets {
+    buckets: Vec<Bucket>,
+    events: Option<EventRing>,
+}
+
+impl CircuitBuckets {
+    fn new(bucket_count: usize, max_events: usize) -> Self {
+        Self {
+            buckets: (0..bucket_count).map(|_| Bucket::default()).collect(),
+            events: (max_events > 0).then(|| EventRing::new(max_events)),
+        }
+    }
+
+    fn recent_events(&self, limit: usize) -> Vec<Event> {
+        self.events
+            .as_ref()
+            .map(|events| events.recent(limit))
+            .unwrap_or_default()
+    }
+}
+
+/// Thread-safe storage that counts events in fixed-width time buckets
+///
+/// Window counts are accurate to one bucket: a query for the last `N` seconds
+/// sums the buckets overlapping that window, including the current, partially
+/// filled one. Windows longer than `bucket_count * bucket_secs` are capped at
+/// the ring size.
+///
+/// Counters are updated with atomic compare-and-swap, so concurrent recording
+/// into the same circuit never blocks. The event log returned by
+/// [`StorageBackend::event_log`] is a separate lock-free ring of `max_events`
+/// slots. It never blocks either, but it is best-effort: under heavy
+/// contention on one circuit an event can be dropped from the log, and a read
+/// racing writers can come back short. The counters stay exact. The log also
+/// backs [`StorageBackend::recent_calls`], so count-based sliding windows
+/// inherit that trade-off; pass `max_events = 0` to
+/// [`BucketedStorage::with_options`] to disable the log entirely.
+///
+/// # Example
+///
+/// ```rust
+/// use breaker_machines::{BucketedStorage, CircuitBreaker};
+/// use std::sync::Arc;
+///
+/// // 120 buckets of 500ms = one minute of history
+/// let storage = Arc::new(BucketedStorage::with_buckets(120, 0.5));
+/// let circuit = CircuitBreaker::builder("api")
+///     .storage(storage)
+///     .failure_window_secs(30.0)
+///     .build();
+/// ```
+#[derive(Debug)]
+pub struct BucketedStorage {
+    /// Bucket rings keyed by circuit name
+    circuits: ShardedMap<CircuitBuckets>,
+    /// Persisted circuit states
+    statuses: StatusTable,
+    /// Number of buckets in each ring
+    bucket_count: usize,
+    /// Width of a single bucket in seconds
+    bucket_secs: f64,
+    /// Maximum events to keep in each circuit's event log
+    max_events: usize,
+    /// Monotonic time source
+    clock: Box<dyn Clock>,
+}
+
+impl BucketedStorage {
+    /// Create storage with 300 one-second buckets and a 100-event log
+    pub fn new() -> Self {
+        Self::with_buckets(DEFAULT_BUCKET_COUNT, DEFAULT_BUCKET_SECS)
+    }
+
+    /// Create storage with a custom bucket count and bucket width
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bucket_count` is 0 or `bucket_secs` is not positive.
+    pub fn with_buckets(bucket_count: usize, bucket_secs: f64) -> Self {
+        Self::with_options(
+            bucket_count,
+            bucket_secs,
+            DEFAULT_MAX_EVENTS,
+            default_clock(),
+        )
+    }
+
+    /// Create storage with a custom [`Clock`].
+    pub fn with_clock(clock: Box<dyn Clock>) -> Self {
+        Self::with_options(
+            DEFAULT_BUCKET_COUNT,
+            DEFAULT_BUCKET_SECS,
+            DEFAULT_MAX_EVENTS,
+            clock,
+        )
+    }
+
+    /// Create storage with every parameter specified.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bucket_count` is 0 or `bucket_secs` is not positive.
+    pub fn with_options(
+        bucket_count: usize,
+        bucket_secs: f64,
+        max_events: usize,
+        clock: Box<dyn Clock>,
+    ) -> Self {
+        assert!(bucket_count > 0, "Bucket count must be greater than 0");
+        assert!(bucket_secs > 0.0, "Bucket width must be positive");
+        Self {
+            circuits: ShardedMap::new(),
+            statuses: StatusTable::default(),
+            bucket_count,
+            bucket_secs,
+            max_events,
+            clock,
+        }
+    }
+
+    /// Number of buckets in each circuit's ring
+    pub fn bucket_count(&self) -> usize {
+        self.bucket_count
+    }
+
+    /// Width of a single bucket in seconds
+    pub fn bucket_secs(&self) -> f64 {
+        self.bucket_secs
+    }
+
+    // Private helper methods
+
+    fn current_epoch(&self) -> u64 {
+        (self.monotonic_time() / self.bucket_secs) as u64
+    }
+
+    /// Number of buckets a window spans, clamped to `1..=bucket_count`
+    fn buckets_in_window(&self, window_seconds: f64) -> usize {
+        // `f64::ceil` needs std; round up by hand so this works on no_std too
+        let exact = window_seconds / self.bucket_secs;
+        let whole = exact as usize;
+        let span = if (whole as f64) < exact {
+            whole + 1
+        } else {
+            whole
+        };
+        span.clamp(1, self.bucket_count)
+    }
+
+    fn bucket<'a>(&self, circuit: &'a CircuitBuckets, epoch: u64) -> &'a Bucket {
+        &circuit.buckets[(epoch % self.bucket_count as u64) as usize]
+    }
+
+    fn record_event(&self, circuit_name: &str, kind: EventKind, duration: f64) {
+        let now = self.monotonic_time();
+        let epoch = (now / self.bucket_secs) as u64;
+
+        let circuit = self.circuits.get_or_insert_with(circuit_name, || {
+            CircuitBuckets::new(self.bucket_count, self.max_events)
+        });
+        self.bucket(&circuit, epoch)
+            .counter(kind.into())
+            .increment(epoch);
+
+        if let Some(events) = &circuit.events {
+            events.push(Event {
+                kind,
+                timestamp: now,
+                duration,
+            });
+        }
+    }
+
+    fn count_events(&self, circuit_name: &str, counter: Counter, window_seconds: f64) -> usize {
+        let Some(circuit) = self.circuits.get(circuit_name) else {
+            return 0;
+        };
+
+        let current = self.current_epoch();
+        let span = self.buckets_in_window(window_seconds) as u64;
+
+        (0..span)
+            .map_while(|offset| current.checked_sub(offset))
+            .map(|epoch| self.bucket(&circuit, epoch).counter(counter).count(epoch))
+            .sum()
+    }
+}
+
+impl Default for BucketedStorage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StorageBackend for BucketedStorage {
+    fn record_success(&self, circuit_name: &str, duration: f64) {
+        self.record_event(circuit_name, EventKind::Success, duration);
+    }
+
+    fn record_failure(&self, circuit_name: &str, duration: f64) {
+        self.record_event(circuit_name, EventKind::Failure, duration);
+    }
+
+    fn success_count(&self, circuit_name: &str, window_seconds: f64) -> usize {
+        self.count_events(circuit_name, Counter::Success, window_seconds)
+    }
+
+    fn failure_count(&self, circuit_name: &str, window_seconds: f64) -> usize {
+        self.count_events(circuit_name, Counter::Failure, window_seconds)
+    }
+
+    fn record_slow_call(&self, circuit_name: &str, _duration: f64) {
+        let epoch = self.current_epoch();
+        let circuit = self.circuits.get_or_insert_with(circuit_name, || {
+            CircuitBuckets::new(self.bucket_count, self.max_events)
+        });
+        self.bucket(&circuit, epoch)
+            .counter(Counter::Slow)
+            .increment(epoch);
+    }
+
+    fn slow_call_count(&self, circuit_name: &str, window_seconds: f64) -> usize {
+        self.count_events(circuit_name, Counter::Slow, window_seconds)
+    }
+
+    /// Tallied from the event log, so at most `max_events` calls are counted
+    fn recent_calls(
+        &self,
+        circuit_name: &str,
+        calls: usize,
+        slow_call_secs: Option<f64>,
+    ) -> CallCounts {
+        self.circuits
+            .get(circuit_name)
+            .map(|circuit| CallCounts::tally(&circuit.recent_events(calls), slow_call_secs))
+            .unwrap_or_default()
+    }
+
+    fn clear(&self, circuit_name: &str) {
+        self.circuits.remove(circuit_name);
+        self.statuses.remove(circuit_name);
+    }
+
+    fn clear_all(&self) {
+        self.circuits.clear();
+        self.statuses.clear();
+    }
+
+    fn event_log(&self, circuit_name: &str, limit: usize) -> Vec<Event> {
+        self.circuits
+            .get(circuit_name)
+            .map(|circuit| circuit.recent_events(limit))
+            .unwrap_or_default()
+    }
+
+    fn monotonic_time(&self) -> f64 {
+        self.clock.now_secs()
+    }
+
+    fn get_status(&self, circuit_name: &str) -> Option<CircuitStatus> {
+        self.statuses.get(circuit_name)
+    }
+
+    fn set_status(&self, circuit_name: &str, status: CircuitStatus) {
+        self.statuses.set(circuit_name, status);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::test_support::ManualClock;
+    use std::sync::Arc;
+
+    fn storage_with_clock(
+        bucket_count: usize,
+        max_events: usize,
+    ) -> (BucketedStorage, ManualClock) {
+        let clock = ManualClock::default();
+        let storage =
+            BucketedStorage::with_options(bucket_count, 1.0, max_events, Box::new(clock.clone()));
+        (storage, clock)
+    }
+
+    #[test]
+    fn test_bucketed_storage_record_and_count() {
+        let storage = BucketedStorage::new();
+
+        storage.record_success("test_circuit", 0.1);
+        storage.record_success("test_circuit", 0.2);
+        storage.record_failure("test_circuit", 0.5);
+
+        assert_eq!(storage.success_count("test_circuit", 60.0), 2);
+        assert_eq!(storage.failure_count("test_circuit", 60.0), 1);
+        assert_eq!(storage.failure_count("other_circuit", 60.0), 0);
+    }
+
+    #[test]
+    fn test_bucketed_storage_slow_calls_expire_with_window() {
+        let (storage, clock) = storage_with_clock(60, 100);
+
+        clock.set(0.5);
+        storage.record_slow_call("test_circuit", 3.0);
+        clock.set(10.5);
+        storage.record_slow_call("test_circuit", 4.0);
+
+        assert_eq!(storage.slow_call_count("test_circuit", 60.0), 2);
+        assert_eq!(storage.slow_call_count("test_circuit", 5.0), 1);
+        assert_eq!(storage.failure_count("test_circuit", 60.0), 0);
+
+        storage.clear("test_circuit");
+        assert_eq!(storage.slow_call_count("test_circuit", 60.0), 0);
+    }
+
+    #[test]
+    fn test_bucketed_storage_window_expiry() {
+        let (storage, clock) = storage_with_clock(60, 100);
+
+        clock.set(0.5);
+        storage.record_failure("test_circuit", 0.1);
+        clock.set(10.5);
+        storage.record_failure("test_circuit", 0.1);
+        storage.record_failure("test_circuit", 0.1);
+
+        assert_eq!(storage.failure_count("test_circuit", 30.0), 3);
+        assert_eq!(storage.failure_count("test_circuit", 5.0), 2);
+
+        clock.set(35.0);
+        assert_eq!(storage.failure_count("test_circuit", 30.0), 2);
+
+        clock.set(45.0);
+        assert_eq!(storage.failure_count("test_circuit", 30.0), 0);
+    }
+
+    #[test]
+    fn test_bucketed_storage_reuses_stale_buckets() {
+        let (storage, clock) = storage_with_clock(10, 100);
+
+        clock.set(2.0);
+        storage.record_success("test_circuit", 0.1);
+
+        // Same ring slot, one full rotation later: the old count must not leak in
+        clock.set(12.0);
+        storage.record_success("test_circuit", 0.1);
+
+        assert_eq!(storage.success_count("test_circuit", 10.0), 1);
+    }
+
+    #[test]
+    fn test_bucketed_storage_window_capped_at_ring_size() {
+        let (storage, clock) = storage_with_clock(5, 100);
+
+        for second in 0..10 {
+            clock.set(second as f64);
+            storage.record_failure("test_circuit", 0.1);
+        }
+
+        assert_eq!(storage.failure_count("test_circuit", 3600.0), 5);
+    }
+
+    #[test]
+    fn test_bucketed_storage_event_log_is_bounded() {
+        let (storage, _clock) = storage_with_clock(10, 3);
+
+        for _ in 0..5 {
+            storage.record_success("test_circuit", 0.1);
+        }
+        storage.record_failure("test_circuit", 0.2);
+
+        let log = storage.event_log("test_circuit", 10);
+        assert_eq!(log.len(), 3);
+        assert_eq!(log[2].kind, EventKind::Failure);
+
+        // Counters are unaffected by the log bound
+        assert_eq!(storage.success_count("test_circuit", 10.0), 5);
+
+        let last = storage.event_log("test_circuit", 1);
+        assert_eq!(last.len(), 1);
+        assert_eq!(last[0].kind, EventKind::Failure);
+    }
+
+    #[test]
+    fn test_bucketed_storage_concurrent_recording_is_exact() {
+        let storage = Arc::new(BucketedStorage::with_options(
+            60,
+            1.0,
+            0,
+            Box::new(ManualClock::default()),
+        ));
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let storage = Arc::clone(&storage);
+                std::thread::spawn(move || {
+                    for _ in 0..1000 {
+                        storage.record_success("shared", 0.01);
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(storage.success_count("shared", 60.0), 8000);
+        assert!(storage.event_log("shared", 10).is_empty());
+    }
+
+    #[test]
+    fn test_bucketed_storage_concurrent_event_log_is_never_torn() {
+        let storage = Arc::new(BucketedStorage::with_options(
+            60,
+            1.0,
+            16,
+            Box::new(ManualClock::at(7.0)),
+        ));
+        let handles: Vec<_> = (0..8)
+            .map(|thread| {
+                let storage = Arc::clone(&storage);
+                std::thread::spawn(move || {
+                    for _ in 0..1000 {
+                        if thread % 2 == 0 {
+                            storage.record_success("shared", 0.25);
+                        } else {
+                            storage.record_failure("shared", 0.5);
+                        }
+                        let _ = storage.event_log("shared", 16);
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let log = storage.event_log("shared", 100);
+        assert!(!log.is_empty() && log.len() <= 16);
+        for event in log {
+            assert_eq!(event.timestamp, 7.0);
+            let expected = match event.kind {
+                EventKind::Success => 0.25,
+                EventKind::Failure => 0.5,
+            };
+            assert_eq!(event.duration, expected);
+        }
+        assert_eq!(storage.success_count("shared", 60.0), 4000);
+        assert_eq!(storage.failure_count("shared", 60.0), 4000);
+    }
+
+    #[test]
+    fn test_bucketed_storage_clear() {
+        let storage = BucketedStorage::new();
+
+        storage.record_success("a", 0.1);
+        storage.record_success("b", 0.1);
+
+        storage.clear("a");
+        assert_eq!(storage.success_count("a", 60.0), 0);
+        assert_eq!(storage.success_count("b", 60.0), 1);
+
+        storage.clear_all();
+        assert_eq!(storage.success_count("b", 60.0), 0);
+        assert!(storage.event_log("b", 10).is_empty());
+    }
+
+    #[test]
+    fn test_bucketed_storage_with_circuit_breaker() {
+        let storage = Arc::new(BucketedStorage::new());
+        let mut circuit = crate::CircuitBreaker::builder("test")
+            .storage(storage)
+            .failure_threshold(3)
+            .build();
+
+        let _ = circuit.call(|| Err::<(), _>("error 1"));
+        let _ = circuit.call(|| Err::<(), _>("error 2"));
+        assert!(circuit.is_closed());
+
+        let _ = circuit.call(|| Err::<(), _>("error 3"));
+        assert!(circuit.is_open());
+    }
+}
@@ -0,0 +1,495 @@
+//! Storage backend that falls back through several backends in order
+//!
+//! Port of the Ruby `Storage::FallbackChain`: every operation goes to the first
+//! healthy backend, and an error or timeout falls through to the next one.
+//! Each backend has its own small breaker (the Ruby `BackendState`): after
+//! `threshold` consecutive failures it is skipped for `timeout_secs`, then
+//! tried again.
+
+use super::{CallCounts, CircuitStatus, StorageBackend};
+use crate::Event;
+use crate::errors::StorageError;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use spin::RwLock;
+
+/// Default consecutive failures before a backend is skipped (as in Ruby)
+const DEFAULT_THRESHOLD: usize = 3;
+
+/// Default seconds an unhealthy backend is skipped (as in Ruby)
+const DEFAULT_TIMEOUT_SECS: f64 = 30.0;
+
+/// Health of one backend in the chain
+#[derive(Debug, Default)]
+struct BackendState {
+    failure_count: usize,
+    /// Set while the backend is tripped; it is skipped until this time
+    unhealthy_until: Option<f64>,
+}
+
+#[derive(Debug)]
+struct ChainedBackend {
+    storage: Arc<dyn StorageBackend>,
+    state: RwLock<BackendState>,
+}
+
+impl ChainedBackend {
+    /// Whether the backend should be skipped; recovers it once its timeout passed
+    fn is_unhealthy(&self, now: f64) -> bool {
+        let Some(until) = self.state.read().unhealthy_until else {
+            return false;
+        };
+        if now > until {
+            *self.state.write() = BackendState::default();
+            false
+        } else {
+            true
+        }
+    }
+
+    fn record_success(&self) {
+        if self.state.read().failure_count > 0 {
+            *self.state.write() = BackendState::default();
+        }
+    }
+
+    fn record_failure(&self, now: f64, threshold: usize, timeout_secs: f64) {
+        let mut state = self.state.write();
+        state.failure_count += 1;
+        if state.failure_count >= threshold && state.unhealthy_until.is_none() {
+            state.unhealthy_until = Some(now + timeout_secs);
+        }
+    }
+}
+
+/// Storage backend that tries several backends in sequence
+///
+/// Writes and reads go to the first healthy backend. When it reports a
+/// [`StorageError`] (see the `try_*` methods of [`StorageBackend`]), the
+/// operation is retried on the next backend. A backend failing `threshold`
+/// times in a row is skipped for `timeout_secs` seconds.
+///
+/// Time comes from the first backend's
+/// [`monotonic_time`](StorageBackend::monotonic_time), which also times out
+/// unhealthy backends. Backends later in the chain should use a compatible
+/// clock so `opened_at` values persisted through them stay meaningful.
+///
+/// # Example
+///
+/// ```rust
+/// use breaker_machines::{FallbackChainStorage, MemoryStorage, StorageBackend};
+/// use std::sync::Arc;
+///
+/// let primary: Arc<dyn StorageBackend> = Arc::new(MemoryStorage::new());
+/// let local: Arc<dyn StorageBackend> = Arc::new(MemoryStorage::new());
+/// let storage = FallbackChainStorage::new(vec![primary, local]).with_circuit_breaker(5, 60.0);
+///
+/// storage.record_failure("api", 0.1);
+/// assert_eq!(storage.failure_count("api", 60.0), 1);
+/// ```
+#[derive(Debug)]
+pub struct FallbackChainStorage {
+    backends: Vec<ChainedBackend>,
+    threshold: usize,
+    timeout_secs: f64,
+}
+
+impl FallbackChainStorage {
+    /// Create a chain trying `backends` in order
+    ///
+    /// # Panics
+    ///
+    /// Panics if `backends` is empty.
+    pub fn new(backends: Vec<Arc<dyn StorageBackend>>) -> Self {
+        assert!(
+            !backends.is_empty(),
+            "FallbackChainStorage needs at least one backend"
+        );
+        Self {
+            backends: backends
+                .into_iter()
+                .map(|storage| ChainedBackend {
+                    storage,
+                    state: RwLock::new(BackendState::default()),
+                })
+                .collect(),
+            threshold: DEFAULT_THRESHOLD,
+            timeout_secs: DEFAULT_TIMEOUT_SECS,
+        }
+    }
+
+    /// Set how many consecutive failures trip a backend (default 3) and how
+    /// long it is skipped afterwards (default 30s)
+    pub fn with_circuit_breaker(mut self, threshold: usize, timeout_secs: f64) -> Self {
+        self.threshold = threshold.max(1);
+        self.timeout_secs = timeout_secs;
+        self
+    }
+
+    /// Number of backends in the chain
+    pub fn len(&self) -> usize {
+        self.backends.len()
+    }
+
+    /// Always `false`: a chain has at least one backend
+    pub fn is_empty(&self) -> bool {
+        self.backends.is_empty()
+    }
+
+    /// Whether the backend at `index` is currently tried
+    pub fn is_backend_healthy(&self, index: usize) -> bool {
+        let now = self.monotonic_time();
+        self.backends
+            .get(index)
+            .is_some_and(|backend| !backend.is_unhealthy(now))
+    }
+
+    /// Mark every backend healthy again
+    pub fn reset_health(&self) {
+        for backend in &self.backends {
+            *backend.state.write() = BackendState::default();
+        }
+    }
+
+    /// Run `op` on each healthy backend in turn until one succeeds
+    fn execute<T>(
+        &self,
+        op: impl Fn(&dyn StorageBackend) -> Result<T, StorageError>,
+    ) -> Result<T, StorageError> {
+        let now = self.monotonic_time();
+        let mut last_error = None;
+
+        for backend in &self.backends {
+            if backend.is_unhealthy(now) {
+                continue;
+            }
+            match op(backend.storage.as_ref()) {
+                Ok(value) => {
+                    backend.record_success();
+                    return Ok(value);
+                }
+                Err(error) => {
+                    backend.record_failure(now, self.threshold, self.timeout_secs);
+                    last_error = Some(error);
+                }
+            }
+        }
+
+        Err(last_error.unwrap_or(StorageError::AllBackendsUnhealthy))
+    }
+}
+
+impl StorageBackend for FallbackChainStorage {
+    fn record_success(&self, circuit_name: &str, duration: f64) {
+        let _ = self.try_record_success(circuit_name, duration);
+    }
+
+    fn record_failure(&self, circuit_name: &str, duration: f64) {
+        let _ = self.try_record_failure(circuit_name, duration);
+    }
+
+    fn success_count(&self, circuit_name: &str, window_seconds: f64) -> usize {
+        self.try_success_count(circuit_name, window_seconds)
+            .unwrap_or(0)
+    }
+
+    fn failure_count(&self, circuit_name: &str, window_seconds: f64) -> usize {
+        self.try_failure_count(circuit_name, window_seconds)
+            .unwrap_or(0)
+    }
+
+    fn record_slow_call(&self, circuit_name: &str, duration: f64) {
+        let _ = self.try_record_slow_call(circuit_name, duration);
+    }
+
+    fn slow_call_count(&self, circuit_name: &str, window_seconds: f64) -> usize {
+        self.try_slow_call_count(circuit_name, window_seconds)
+            .unwrap_or(0)
+    }
+
+    fn recent_calls(
+        &self,
+        circuit_name: &str,
+        calls: usize,
+        slow_call_secs: Option<f64>,
+    ) -> CallCounts {
+        self.try_recent_calls(circuit_name, calls, slow_call_secs)
+            .unwrap_or_default()
+    }
+
+    fn clear(&self, circuit_name: &str) {
+        let _ = self.try_clear(circuit_name);
+    }
+
+    fn clear_all(&self) {
+        let _ = self.try_clear_all();
+    }
+
+    fn event_log(&self, circuit_name: &str, limit: usize) -> Vec<Event> {
+        let now = self.monotonic_time();
+        self.backends
+            .iter()
+            .find(|backend| !backend.is_unhealthy(now))
+            .map(|backend| backend.storage.event_log(circuit_name, limit))
+            .unwrap_or_default()
+    }
+
+    fn monotonic_time(&self) -> f64 {
+        self.backends[0].storage.monotonic_time()
+    }
+
+    fn get_status(&self, circuit_name: &str) -> Option<CircuitStatus> {
+        self.try_get_status(circuit_name).ok().flatten()
+    }
+
+    fn set_status(&self, circuit_name: &str, status: CircuitStatus) {
+        let _ = self.try_set_status(circuit_name, status);
+    }
+
+    fn shares_state(&self) -> bool {
+        self.backends
+            .iter()
+            .any(|backend| backend.storage.shares_state())
+    }
+
+    fn try_record_success(&self, circuit_name: &str, duration: f64) -> Result<(), StorageError> {
+        self.execute(|storage| storage.try_record_success(circuit_name, duration))
+    }
+
+    fn try_record_failure(&self, circuit_name: &str, duration: f64) -> Result<(), StorageError> {
+        self.execute(|storage| storage.try_record_failure(circuit_name, duration))
+    }
+
+    fn try_success_count(
+        &self,
+        circuit_name: &str,
+        window_seconds: f64,
+    ) -> Result<usize, StorageError> {
+        self.execute(|storage| storage.try_success_count(circuit_name, window_seconds))
+    }
+
+    fn try_failure_count(
+        &self,
+        circuit_name: &str,
+        window_seconds: f64,
+    ) -> Result<usize, StorageError> {
+        self.execute(|storage| storage.try_failure_count(circuit_name, window_seconds))
+    }
+
+    fn try_record_slow_call(&self, circuit_name: &str, duration: f64) -> Result<(), StorageError> {
+        self.execute(|storage| storage.try_record_slow_call(circuit_name, duration))
+    }
+
+    fn try_slow_call_count(
+        &self,
+        circuit_name: &str,
+        window_seconds: f64,
+    ) -> Result<usize, StorageError> {
+        self.execute(|storage| storage.try_slow_call_count(circuit_name, window_seconds))
+    }
+
+    fn try_recent_calls(
+        &self,
+        circuit_name: &str,
+        calls: usize,
+        slow_call_secs: Option<f64>,
+    ) -> Result<CallCounts, StorageError> {
+        self.execute(|storage| storage.try_recent_calls(circuit_name, calls, slow_call_secs))
+    }
+
+    fn try_clear(&self, circuit_name: &str) -> Result<(), StorageError> {
+        self.execute(|storage| storage.try_clear(circuit_name))
+    }
+
+    fn try_clear_all(&self) -> Result<(), StorageError> {
+        self.execute(|storage| storage.try_clear_all())
+    }
+
+    fn try_get_status(&self, circuit_name: &str) -> Result<Option<CircuitStatus>, StorageError> {
+        self.execute(|storage| storage.try_get_status(circuit_name))
+    }
+
+    fn try_set_status(
+        &self,
+        circuit_name: &str,
+        status: CircuitStatus,
+    ) -> Result<(), StorageError> {
+        self.execute(|storage| storage.try_set_status(circuit_name, status))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::MemoryStorage;
+    use crate::time::test_support::ManualClock;
+    use alloc::boxed::Box;
+    use core::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+    /// Memory backend that can be switched into an outage
+    #[derive(Debug)]
+    struct FlakyStorage {
+        inner: MemoryStorage,
+        down: AtomicBool,
+        attempts: AtomicU64,
+    }
+
+    impl FlakyStorage {
+        fn new(clock: &ManualClock) -> Self {
+            Self {
+                inner: MemoryStorage::with_clock(Box::new(clock.clone())),
+                down: AtomicBool::new(false),
+                attempts: AtomicU64::new(0),
+            }
+        }
+
+        fn set_down(&self, down: bool) {
+            self.down.store(down, Ordering::SeqCst);
+        }
+
+        fn attempts(&self) -> u64 {
+            self.attempts.load(Ordering::SeqCst)
+        }
+
+        fn check(&self) -> Result<(), StorageError> {
+            self.attempts.fetch_add(1, Ordering::SeqCst);
+            if self.down.load(Ordering::SeqCst) {
+                Err(StorageError::Timeout)
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    impl StorageBackend for FlakyStorage {
+        fn record_success(&self, circuit_name: &str, duration: f64) {
+            self.inner.record_success(circuit_name, duration);
+        }
+
+        fn record_failure(&self, circuit_name: &str, duration: f64) {
+            self.inner.record_failure(circuit_name, duration);
+        }
+
+        fn success_count(&self, circuit_name: &str, window_seconds: f64) -> usize {
+            self.inner.success_count(circuit_name, window_seconds)
+        }
+
+        fn failure_count(&self, circuit_name: &str, window_seconds: f64) -> usize {
+            self.inner.failure_count(circuit_name, window_seconds)
+        }
+
+        fn clear(&self, circuit_name: &str) {
+            self.inner.clear(circuit_name);
+        }
+
+        fn clear_all(&self) {
+            self.inner.clear_all();
+        }
+
+        fn event_log(&self, circuit_name: &str, limit: usize) -> Vec<Event> {
+            self.inner.event_log(circuit_name, limit)
+        }
+
+        fn monotonic_time(&self) -> f64 {
+            self.inner.monotonic_time()
+        }
+
+        fn try_record_failure(
+            &self,
+            circuit_name: &str,
+            duration: f64,
+        ) -> Result<(), StorageError> {
+            self.check()?;
+            self.inner.try_record_failure(circuit_name, duration)
+        }
+
+        fn try_failure_count(
+            &self,
+            circuit_name: &str,
+            window_seconds: f64,
+        ) -> Result<usize, StorageError> {
+            self.check()?;
+            self.inner.try_failure_count(circuit_name, window_seconds)
+        }
+    }
+
+    fn chain(clock: &ManualClock) -> (Arc<FlakyStorage>, Arc<FlakyStorage>, FallbackChainStorage) {
+        let primary = Arc::new(FlakyStorage::new(clock));
+        let secondary = Arc::new(FlakyStorage::new(clock));
+        let storage = FallbackChainStorage::new(alloc::vec![
+            Arc::clone(&primary) as Arc<dyn StorageBackend>,
+            Arc::clone(&secondary) as Arc<dyn StorageBackend>,
+        ])
+        .with_circuit_breaker(2, 30.0);
+        (primary, secondary, storage)
+    }
+
+    #[test]
+    fn test_chain_uses_primary_while_healthy() {
+        let clock = ManualClock::default();
+        let (primary, secondary, storage) = chain(&clock);
+
+        storage.record_failure("api", 0.1);
+        assert_eq!(storage.failure_count("api", 60.0), 1);
+        assert_eq!(primary.inner.failure_count("api", 60.0), 1);
+        assert_eq!(secondary.attempts(), 0);
+    }
+
+    #[test]
+    fn test_chain_falls_through_on_error() {
+        let clock = ManualClock::default();
+        let (primary, secondary, storage) = chain(&clock);
+        primary.set_down(true);
+
+        assert_eq!(storage.try_record_failure("api", 0.1), Ok(()));
+        assert_eq!(secondary.inner.failure_count("api", 60.0), 1);
+        assert_eq!(storage.try_failure_count("api", 60.0), Ok(1));
+    }
+
+    #[test]
+    fn test_chain_skips_tripped_backend_until_timeout() {
+        let clock = ManualClock::default();
+        let (primary, _secondary, storage) = chain(&clock);
+        primary.set_down(true);
+
+        storage.record_failure("api", 0.1);
+        storage.record_failure("api", 0.1);
+        assert!(!storage.is_backend_healthy(0));
+        assert_eq!(primary.attempts(), 2);
+
+        storage.record_failure("api", 0.1);
+        assert_eq!(primary.attempts(), 2, "tripped backend should be skipped");
+
+        primary.set_down(false);
+        clock.advance(31.0);
+        assert!(storage.is_backend_healthy(0));
+        storage.record_failure("api", 0.1);
+        assert_eq!(primary.attempts(), 3);
+        assert_eq!(primary.inner.failure_count("api", 60.0), 1);
+    }
+
+    #[test]
+    fn test_chain_reports_last_error_when_all_fail() {
+        let clock = ManualClock::default();
+        let (primary, secondary, storage) = chain(&clock);
+        primary.set_down(true);
+        secondary.set_down(true);
+
+        assert_eq!(
+            storage.try_record_failure("api", 0.1),
+            Err(StorageError::Timeout)
+        );
+        assert_eq!(
+            storage.try_record_failure("api", 0.1),
+            Err(StorageError::Timeout)
+        );
+        assert_eq!(
+            storage.try_record_failure("api", 0.1),
+            Err(StorageError::AllBackendsUnhealthy)
+        );
+        assert_eq!(storage.failure_count("api", 60.0), 0);
+
+        storage.reset_health();
+        assert!(storage.is_backend_healthy(0) && storage.is_backend_healthy(1));
+    }
+}
@@ -0,0 +1,1170 @@
+//! Redis-protocol (RESP) storage backend
+//!
+//! Failure and success counts live in per-second bucket keys on a Redis (or
+//! any RESP-compatible) server, so a fleet of processes on different hosts
+//! shares one view of each circuit. The key scheme mirrors the Ruby
+//! `Storage::Cache` backend:
+//!
+//! - `{prefix}:{circuit}:status`
+//! - `{prefix}:{circuit}:successes:{bucket}`
+//! - `{prefix}:{circuit}:failures:{bucket}`
+//! - `{prefix}:{circuit}:slow_calls:{bucket}`
+//! - `{prefix}:{circuit}:recent`
+//!
+//! where `{bucket}` is the Unix time in whole seconds. `recent` is a capped
+//! list of the latest calls (`s:{duration}` or `f:{duration}`, newest first)
+//! used by count-based windows.
+
+use super::{CallCounts, CircuitStatus, StorageBackend};
+use crate::circuit::CircuitState;
+use crate::errors::StorageError;
+use crate::time::Clock;
+use crate::{Event, EventKind};
+use std::boxed::Box;
+use std::format;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::string::{String, ToString};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use std::vec::Vec;
+
+/// Default key prefix, shared with the Ruby cache backend
+const DEFAULT_PREFIX: &str = "breaker_machines";
+
+/// Default key expiration in seconds (5 minutes, as in the Ruby backend)
+const DEFAULT_EXPIRES_IN_SECS: u64 = 300;
+
+/// Counter names used in bucket keys
+const SUCCESSES: &str = "successes";
+const FAILURES: &str = "failures";
+const SLOW_CALLS: &str = "slow_calls";
+
+/// Default length of the recent-calls list
+const DEFAULT_MAX_RECENT_CALLS: usize = 100;
+
+/// Default connect/read/write timeout
+const DEFAULT_TIMEOUT: Duration = Duration::from_millis(250);
+
+/// Default number of idle connections kept for reuse
+const DEFAULT_POOL_SIZE: usize = 4;
+
+/// Default time to fail fast after the server could not be reached
+const DEFAULT_RECONNECT_COOLDOWN: Duration = Duration::from_secs(1);
+
+/// Wall-clock [`Clock`] in seconds since the Unix epoch.
+///
+/// Hosts in a fleet share no monotonic clock, so timestamps stored on the
+/// server use wall time. Readings never go backwards within one process even
+/// if the system clock is stepped back.
+#[derive(Debug, Default)]
+struct WallClock {
+    last_micros: AtomicU64,
+}
+
+impl Clock for WallClock {
+    fn now_secs(&self) -> f64 {
+        let micros = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_micros() as u64)
+            .unwrap_or(0);
+        let previous = self.last_micros.fetch_max(micros, Ordering::AcqRel);
+        micros.max(previous) as f64 / 1e6
+    }
+}
+
+/// A single RESP reply
+#[derive(Debug, Clone, PartialEq)]
+enum Reply {
+    Simple(String),
+    Integer(i64),
+    Bulk(Option<Vec<u8>>),
+    Array(Option<Vec<Reply>>),
+}
+
+impl Reply {
+    fn into_bytes(self) -> Option<Vec<u8>> {
+        match self {
+            Reply::Bulk(bytes) => bytes,
+            Reply::Simple(s) => Some(s.into_bytes()),
+            _ => None,
+        }
+    }
+
+    fn as_count(&self) -> usize {
+        match self {
+            Reply::Integer(n) => (*n).max(0) as usize,
+            Reply::Bulk(Some(bytes)) => core::str::from_utf8(bytes)
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(0),
+            _ => 0,
+        }
+    }
+}
+
+/// One open connection to the server
+struct Connection {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+impl Connection {
+    fn open(addr: &str, timeout: Duration) -> io::Result<Self> {
+        let mut last_error = None;
+        for socket_addr in std::net::ToSocketAddrs::to_socket_addrs(addr)? {
+            match TcpStream::connect_timeout(&socket_addr, timeout) {
+                Ok(stream) => {
+                    stream.set_read_timeout(Some(timeout))?;
+                    stream.set_write_timeout(Some(timeout))?;
+                    stream.set_nodelay(true)?;
+                    return Ok(Self {
+                        writer: stream.try_clone()?,
+                        reader: BufReader::new(stream),
+                    });
+                }
+                Err(e) => last_error = Some(e),
+            }
+        }
+        Err(last_error.unwrap_or_else(|| {
+            io::Error::new(io::ErrorKind::NotFound, "address resolved to nothing")
+        }))
+    }
+
+    /// Send several commands in one write and read all their replies
+    fn pipeline(&mut self, commands: &[Vec<Vec<u8>>]) -> io::Result<Vec<Reply>> {
+        let mut buf = Vec::new();
+        for args in commands {
+            encode_command(&mut buf, args);
+        }
+        self.writer.write_all(&buf)?;
+
+        commands
+            .iter()
+            .map(|_| read_reply(&mut self.reader))
+            .collect()
+    }
+
+    fn command(&mut self, args: Vec<Vec<u8>>) -> io::Result<Reply> {
+        let mut replies = self.pipeline(&[args])?;
+        replies
+            .pop()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "missing reply"))
+    }
+}
+
+fn encode_command(buf: &mut Vec<u8>, args: &[Vec<u8>]) {
+    buf.extend_from_slice(format!("*{}\r\n", args.len()).as_bytes());
+    for arg in args {
+        buf.extend_from_slice(format!("${}\r\n", arg.len()).as_bytes());
+        buf.extend_from_slice(arg);
+        buf.extend_from_slice(b"\r\n");
+    }
+}
+
+fn read_line(reader: &mut impl BufRead) -> io::Result<String> {
+    let mut line = String::new();
+    if reader.read_line(&mut line)? == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "connection closed",
+        ));
+    }
+    let trimmed_len = line.trim_end_matches(['\r', '\n']).len();
+    line.truncate(trimmed_len);
+    Ok(line)
+}
+
+fn parse_len(text: &str) -> io::Result<i64> {
+    text.parse()
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "invalid RESP length"))
+}
+
+fn read_reply(reader: &mut impl BufRead) -> io::Result<Reply> {
+    let line = read_line(reader)?;
+    let (kind, rest) = line.split_at(line.len().min(1));
+    match kind {
+        "+" => Ok(Reply::Simple(rest.to_string())),
+        "-" => Err(io::Error::other(format!("server error: {rest}"))),
+        ":" => Ok(Reply::Integer(parse_len(rest)?)),
+        "$" => {
+            let len = parse_len(rest)?;
+            if len < 0 {
+                return Ok(Reply::Bulk(None));
+            }
+            let mut data = std::vec![0u8; len as usize + 2];
+            reader.read_exact(&mut data)?;
+            data.truncate(len as usize);
+            Ok(Reply::Bulk(Some(data)))
+        }
+        "*" => {
+            let len = parse_len(rest)?;
+            if len < 0 {
+                return Ok(Reply::Array(None));
+            }
+            (0..len)
+                .map(|_| read_reply(reader))
+                .collect::<io::Result<Vec<_>>>()
+                .map(|items| Reply::Array(Some(items)))
+        }
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "unknown RESP reply type",
+        )),
+    }
+}
+
+fn storage_error(error: io::Error) -> StorageError {
+    match error.kind() {
+        io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock => StorageError::Timeout,
+        _ => StorageError::Unavailable(error.to_string()),
+    }
+}
+
+fn arg(value: impl ToString) -> Vec<u8> {
+    value.to_string().into_bytes()
+}
+
+/// `closed`, `half_open:{attempt}` or `open:{opened_at}:{attempt}:{timeout}`,
+/// with an empty timeout when none was drawn
+fn encode_status(status: CircuitStatus) -> String {
+    match status.state {
+        CircuitState::Closed => "closed".to_string(),
+        CircuitState::HalfOpen => format!("half_open:{}", status.attempt),
+        CircuitState::Open => format!(
+            "open:{}:{}:{}",
+            status.opened_at.unwrap_or(0.0),
+            status.attempt,
+            status
+                .timeout_secs
+                .map(|secs| secs.to_string())
+                .unwrap_or_default()
+        ),
+    }
+}
+
+/// Parse a recent-calls entry (`s:{duration}` or `f:{duration}`)
+fn decode_call(raw: &[u8]) -> Option<(EventKind, f64)> {
+    let (code, duration) = core::str::from_utf8(raw).ok()?.split_once(':')?;
+    let kind = match code {
+        "s" => EventKind::Success,
+        "f" => EventKind::Failure,
+        _ => return None,
+    };
+    Some((kind, duration.parse().unwrap_or(0.0)))
+}
+
+/// Parse an encoded status; the attempt and timeout are optional, so values
+/// written before they were stored still decode
+fn decode_status(raw: &str) -> Option<CircuitStatus> {
+    let (state, rest) = raw.split_once(':').unwrap_or((raw, ""));
+    let mut fields = rest.split(':');
+    let mut status = match state {
+        "closed" => return Some(CircuitStatus::new(CircuitState::Closed)),
+        "half_open" => CircuitStatus::new(CircuitState::HalfOpen),
+        "open" => CircuitStatus::open(fields.next()?.parse().ok()?),
+        _ => return None,
+    };
+    status.attempt = fields
+        .next()
+        .and_then(|attempt| attempt.parse().ok())
+        .unwrap_or(0);
+    status.timeout_secs = fields.next().and_then(|secs| secs.parse().ok());
+    Some(status)
+}
+
+/// Storage backend that keeps counters and state on a RESP server
+///
+/// Each recorded event is an `INCR` on the current second's bucket key plus an
+/// `EXPIRE`; successes and failures are also pushed onto the capped
+/// recent-calls list. The commands are pipelined in one round trip. Window counts `MGET` the bucket keys
+/// covering the window. Circuit state is stored under the status key, and
+/// circuits re-read it before every call, so an outage seen by one host opens
+/// the circuit fleet-wide.
+///
+/// Each operation checks a connection out of a small pool and returns it
+/// afterwards, so concurrent calls for different circuits don't wait on each
+/// other's round trips. Connections are opened lazily, and one that fails an
+/// operation is dropped rather than returned. When a connection can't be
+/// opened or the server stops answering in time, every operation fails fast
+/// with [`StorageError::Unavailable`] for a reconnect cooldown instead of
+/// waiting out the timeout again. While the server is unreachable, the
+/// `try_*` methods report a [`StorageError`]; the infallible ones drop
+/// recordings and read counts as zero. [`StorageBackend::event_log`] always
+/// returns an empty log.
+///
+/// # Time
+///
+/// [`StorageBackend::monotonic_time`] is wall-clock time in seconds since the
+/// Unix epoch, so `opened_at` is comparable across hosts. Keep fleet clocks
+/// synchronized (NTP); within one process readings never go backwards.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use breaker_machines::{CircuitBreaker, RespStorage};
+/// use std::sync::Arc;
+///
+/// let storage = Arc::new(RespStorage::new("127.0.0.1:6379").with_prefix("checkout"));
+/// let mut circuit = CircuitBreaker::builder("payments")
+///     .storage(storage)
+///     .failure_threshold(20)
+///     .build();
+/// ```
+pub struct RespStorage {
+    addr: String,
+    prefix: String,
+    expires_in_secs: u64,
+    max_recent_calls: usize,
+    timeout: Duration,
+    /// Idle connections ready for reuse
+    pool: Mutex<Vec<Connection>>,
+    pool_size: usize,
+    reconnect_cooldown: Duration,
+    /// Reference point for `retry_at_micros`
+    origin: Instant,
+    /// Microseconds after `origin` before which no connection is attempted;
+    /// 0 when the server is considered reachable
+    retry_at_micros: AtomicU64,
+    clock: Box<dyn Clock>,
+}
+
+impl RespStorage {
+    /// Create a backend for the server at `addr` (e.g. `"127.0.0.1:6379"`)
+    ///
+    /// No connection is made until the first operation.
+    pub fn new(addr: impl Into<String>) -> Self {
+        Self {
+            addr: addr.into(),
+            prefix: DEFAULT_PREFIX.to_string(),
+            expires_in_secs: DEFAULT_EXPIRES_IN_SECS,
+            max_recent_calls: DEFAULT_MAX_RECENT_CALLS,
+            timeout: DEFAULT_TIMEOUT,
+            pool: Mutex::new(Vec::new()),
+            pool_size: DEFAULT_POOL_SIZE,
+            reconnect_cooldown: DEFAULT_RECONNECT_COOLDOWN,
+            origin: Instant::now(),
+            retry_at_micros: AtomicU64::new(0),
+            clock: Box::new(WallClock::default()),
+        }
+    }
+
+    /// Set the key prefix (default `"breaker_machines"`)
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Set how long keys live, in seconds (default 300)
+    ///
+    /// Windows longer than this are effectively capped, since older buckets
+    /// have expired.
+    pub fn with_expires_in_secs(mut self, secs: u64) -> Self {
+        self.expires_in_secs = secs.max(1);
+        self
+    }
+
+    /// Set how many recent calls are kept for count-based windows (default 100)
+    ///
+    /// Count-based windows larger than this see only the last `calls`. Set to
+    /// 0 to skip maintaining the list when no circuit uses one.
+    pub fn with_max_recent_calls(mut self, calls: usize) -> Self {
+        self.max_recent_calls = calls;
+        self
+    }
+
+    /// Set the connect, read and write timeout (default 250ms)
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set how many idle connections are kept for reuse (default 4)
+    ///
+    /// More concurrent operations than this still run in parallel; the extra
+    /// connections are closed once they are done.
+    pub fn with_pool_size(mut self, size: usize) -> Self {
+        self.pool_size = size;
+        self
+    }
+
+    /// Set how long operations fail fast after the server could not be
+    /// reached before a new connection is attempted (default 1s)
+    pub fn with_reconnect_cooldown(mut self, cooldown: Duration) -> Self {
+        self.reconnect_cooldown = cooldown;
+        self
+    }
+
+    /// Replace the wall-clock time source, mainly for tests
+    pub fn with_clock(mut self, clock: Box<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    // Private helper methods
+
+    fn status_key(&self, circuit_name: &str) -> String {
+        format!("{}:{circuit_name}:status", self.prefix)
+    }
+
+    fn recent_key(&self, circuit_name: &str) -> String {
+        format!("{}:{circuit_name}:recent", self.prefix)
+    }
+
+    fn counter_key(&self, circuit_name: &str, counter: &str, bucket: u64) -> String {
+        format!("{}:{circuit_name}:{counter}:{bucket}", self.prefix)
+    }
+
+    fn current_bucket(&self) -> u64 {
+        self.monotonic_time() as u64
+    }
+
+    fn lock_pool(&self) -> std::sync::MutexGuard<'_, Vec<Connection>> {
+        self.pool
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    fn elapsed_micros(&self) -> u64 {
+        self.origin.elapsed().as_micros() as u64
+    }
+
+    /// Take an idle connection, or open one unless the server is cooling down
+    fn checkout(&self) -> Result<Connection, StorageError> {
+        if let Some(connection) = self.lock_pool().pop() {
+            return Ok(connection);
+        }
+
+        let retry_at = self.retry_at_micros.load(Ordering::Acquire);
+        if retry_at != 0 && self.elapsed_micros() < retry_at {
+            return Err(StorageError::Unavailable(format!(
+                "{} unreachable, waiting to reconnect",
+                self.addr
+            )));
+        }
+
+        let connection = Connection::open(&self.addr, self.timeout).map_err(|error| {
+            self.start_cooldown();
+            storage_error(error)
+        })?;
+        self.retry_at_micros.store(0, Ordering::Release);
+        Ok(connection)
+    }
+
+    fn checkin(&self, connection: Connection) {
+        let mut pool = self.lock_pool();
+        if pool.len() < self.pool_size {
+            pool.push(connection);
+        }
+    }
+
+    fn start_cooldown(&self) {
+        let retry_at = self.elapsed_micros() + self.reconnect_cooldown.as_micros() as u64;
+        // Never 0, which means "reachable"
+        self.retry_at_micros
+            .store(retry_at.max(1), Ordering::Release);
+    }
+
+    /// Run `f` on a pooled connection. The pool lock is held only to take and
+    /// return the connection, never across I/O. A failed operation drops its
+    /// connection so the next call starts clean, and a timeout also starts
+    /// the reconnect cooldown.
+    fn with_connection<R>(
+        &self,
+        f: impl FnOnce(&mut Connection) -> io::Result<R>,
+    ) -> Result<R, StorageError> {
+        let mut connection = self.checkout()?;
+        match f(&mut connection) {
+            Ok(value) => {
+                self.checkin(connection);
+                Ok(value)
+            }
+            Err(error) => {
+                let error = storage_error(error);
+                if error == StorageError::Timeout {
+                    // Idle connections lead to the same unresponsive server
+                    self.lock_pool().clear();
+                    self.start_cooldown();
+                }
+                Err(error)
+            }
+        }
+    }
+
+    fn record_event(&self, circuit_name: &str, counter: &str) -> Result<(), StorageError> {
+        let key = self.counter_key(circuit_name, counter, self.current_bucket());
+        self.with_connection(|conn| {
+            conn.pipeline(&[
+                std::vec![arg("INCR"), arg(&key)],
+                std::vec![arg("EXPIRE"), arg(&key), arg(self.expires_in_secs)],
+            ])
+        })
+        .map(|_| ())
+    }
+
+    /// Count a success or failure and push it onto the recent-calls list,
+    /// in one round trip
+    fn record_call(
+        &self,
+        circuit_name: &str,
+        kind: EventKind,
+        duration: f64,
+    ) -> Result<(), StorageError> {
+        let (counter, code) = match kind {
+            EventKind::Success => (SUCCESSES, 's'),
+            EventKind::Failure => (FAILURES, 'f'),
+        };
+        if self.max_recent_calls == 0 {
+            return self.record_event(circuit_name, counter);
+        }
+
+        let key = self.counter_key(circuit_name, counter, self.current_bucket());
+        let recent = self.recent_key(circuit_name);
+        self.with_connection(|conn| {
+            conn.pipeline(&[
+                std::vec![arg("INCR"), arg(&key)],
+                std::vec![arg("EXPIRE"), arg(&key), arg(self.expires_in_secs)],
+                std::vec![
+                    arg("LPUSH"),
+                    arg(&recent),
+                    arg(format!("{code}:{duration}"))
+                ],
+                std::vec![
+                    arg("LTRIM"),
+                    arg(&recent),
+                    arg(0),
+                    arg(self.max_recent_calls - 1)
+                ],
+                std::vec![arg("EXPIRE"), arg(&recent), arg(self.expires_in_secs)],
+            ])
+        })
+        .map(|_| ())
+    }
+
+    fn count_events(
+        &self,
+        circuit_name: &str,
+        counter: &str,
+        window_seconds: f64,
+    ) -> Result<usize, StorageError> {
+        let current = self.current_bucket();
+        let span = (window_seconds.ceil() as u64).clamp(1, self.expires_in_secs);
+
+        let mut args = std::vec![arg("MGET")];
+        args.extend(
+            (0..span)
+                .map_while(|offset| current.checked_sub(offset))
+                .map(|bucket| arg(self.counter_key(circuit_name, counter, bucket))),
+        );
+
+        match self.with_connection(|conn| conn.command(args))? {
+            Reply::Array(Some(values)) => Ok(values.iter().map(Reply::as_count).sum()),
+            _ => Err(StorageError::Unavailable(
+                "unexpected MGET reply".to_string(),
+            )),
+        }
+    }
+
+    /// Every key this backend may hold for a circuit
+    fn circuit_keys(&self, circuit_name: &str) -> Vec<Vec<u8>> {
+        let current = self.current_bucket();
+        let mut keys = std::vec![
+            arg(self.status_key(circuit_name)),
+            arg(self.recent_key(circuit_name)),
+        ];
+        for offset in 0..=self.expires_in_secs {
+            let Some(bucket) = current.checked_sub(offset) else {
+                break;
+            };
+            for counter in [SUCCESSES, FAILURES, SLOW_CALLS] {
+                keys.push(arg(self.counter_key(circuit_name, counter, bucket)));
+            }
+        }
+        keys
+    }
+}
+
+impl core::fmt::Debug for RespStorage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RespStorage")
+            .field("addr", &self.addr)
+            .field("prefix", &self.prefix)
+            .field("expires_in_secs", &self.expires_in_secs)
+            .field("max_recent_calls", &self.max_recent_calls)
+            .field("timeout", &self.timeout)
+            .field("pool_size", &self.pool_size)
+            .field("reconnect_cooldown", &self.reconnect_cooldown)
+            .finish()
+    }
+}
+
+impl StorageBackend for RespStorage {
+    fn record_success(&self, circuit_name: &str, duration: f64) {
+        let _ = self.try_record_success(circuit_name, duration);
+    }
+
+    fn record_failure(&self, circuit_name: &str, duration: f64) {
+        let _ = self.try_record_failure(circuit_name, duration);
+    }
+
+    fn success_count(&self, circuit_name: &str, window_seconds: f64) -> usize {
+        self.try_success_count(circuit_name, window_seconds)
+            .unwrap_or(0)
+    }
+
+    fn failure_count(&self, circuit_name: &str, window_seconds: f64) -> usize {
+        self.try_failure_count(circuit_name, window_seconds)
+            .unwrap_or(0)
+    }
+
+    fn record_slow_call(&self, circuit_name: &str, duration: f64) {
+        let _ = self.try_record_slow_call(circuit_name, duration);
+    }
+
+    fn slow_call_count(&self, circuit_name: &str, window_seconds: f64) -> usize {
+        self.try_slow_call_count(circuit_name, window_seconds)
+            .unwrap_or(0)
+    }
+
+    fn recent_calls(
+        &self,
+        circuit_name: &str,
+        calls: usize,
+        slow_call_secs: Option<f64>,
+    ) -> CallCounts {
+        self.try_recent_calls(circuit_name, calls, slow_call_secs)
+            .unwrap_or_default()
+    }
+
+    fn clear(&self, circuit_name: &str) {
+        let _ = self.try_clear(circuit_name);
+    }
+
+    fn clear_all(&self) {
+        let _ = self.try_clear_all();
+    }
+
+    fn event_log(&self, _circuit_name: &str, _limit: usize) -> Vec<Event> {
+        Vec::new()
+    }
+
+    fn monotonic_time(&self) -> f64 {
+        self.clock.now_secs()
+    }
+
+    fn get_status(&self, circuit_name: &str) -> Option<CircuitStatus> {
+        self.try_get_status(circuit_name).ok().flatten()
+    }
+
+    fn set_status(&self, circuit_name: &str, status: CircuitStatus) {
+        let _ = self.try_set_status(circuit_name, status);
+    }
+
+    fn shares_state(&self) -> bool {
+        true
+    }
+
+    fn try_record_success(&self, circuit_name: &str, duration: f64) -> Result<(), StorageError> {
+        self.record_call(circuit_name, EventKind::Success, duration)
+    }
+
+    fn try_record_failure(&self, circuit_name: &str, duration: f64) -> Result<(), StorageError> {
+        self.record_call(circuit_name, EventKind::Failure, duration)
+    }
+
+    fn try_success_count(
+        &self,
+        circuit_name: &str,
+        window_seconds: f64,
+    ) -> Result<usize, StorageError> {
+        self.count_events(circuit_name, SUCCESSES, window_seconds)
+    }
+
+    fn try_failure_count(
+        &self,
+        circuit_name: &str,
+        window_seconds: f64,
+    ) -> Result<usize, StorageError> {
+        self.count_events(circuit_name, FAILURES, window_seconds)
+    }
+
+    fn try_record_slow_call(&self, circuit_name: &str, _duration: f64) -> Result<(), StorageError> {
+        self.record_event(circuit_name, SLOW_CALLS)
+    }
+
+    fn try_slow_call_count(
+        &self,
+        circuit_name: &str,
+        window_seconds: f64,
+    ) -> Result<usize, StorageError> {
+        self.count_events(circuit_name, SLOW_CALLS, window_seconds)
+    }
+
+    fn try_recent_calls(
+        &self,
+        circuit_name: &str,
+        calls: usize,
+        slow_call_secs: Option<f64>,
+    ) -> Result<CallCounts, StorageError> {
+        let len = calls.min(self.max_recent_calls);
+        if len == 0 {
+            return Ok(CallCounts::default());
+        }
+
+        let args = std::vec![
+            arg("LRANGE"),
+            arg(self.recent_key(circuit_name)),
+            arg(0),
+            arg(len - 1),
+        ];
+        let Reply::Array(Some(entries)) = self.with_connection(|conn| conn.command(args))? else {
+            return Err(StorageError::Unavailable(
+                "unexpected LRANGE reply".to_string(),
+            ));
+        };
+
+        let mut counts = CallCounts::default();
+        for entry in entries.into_iter().filter_map(Reply::into_bytes) {
+            if let Some((kind, duration)) = decode_call(&entry) {
+                counts.add(kind, duration, slow_call_secs);
+            }
+        }
+        Ok(counts)
+    }
+
+    fn try_clear(&self, circuit_name: &str) -> Result<(), StorageError> {
+        let mut args = std::vec![arg("DEL")];
+        args.extend(self.circuit_keys(circuit_name));
+        self.with_connection(|conn| conn.command(args)).map(|_| ())
+    }
+
+    fn try_clear_all(&self) -> Result<(), StorageError> {
+        let pattern = format!("{}:*", self.prefix);
+        self.with_connection(|conn| {
+            let mut cursor = "0".to_string();
+            loop {
+                let reply = conn.command(std::vec![
+                    arg("SCAN"),
+                    arg(&cursor),
+                    arg("MATCH"),
+                    arg(&pattern),
+                    arg("COUNT"),
+                    arg(500),
+                ])?;
+                let Reply::Array(Some(mut parts)) = reply else {
+                    return Err(io::Error::new(io::ErrorKind::InvalidData, "bad SCAN reply"));
+                };
+                let keys = match parts.pop() {
+                    Some(Reply::Array(Some(keys))) => keys,
+                    _ => Vec::new(),
+                };
+                let next = parts
+                    .pop()
+                    .and_then(Reply::into_bytes)
+                    .and_then(|bytes| String::from_utf8(bytes).ok())
+                    .unwrap_or_else(|| "0".to_string());
+
+                let keys: Vec<Vec<u8>> = keys.into_iter().filter_map(Reply::into_bytes).collect();
+                if !keys.is_empty() {
+                    let mut args = std::vec![arg("DEL")];
+                    args.extend(keys);
+                    conn.command(args)?;
+                }
+
+                if next == "0" {
+                    return Ok(());
+                }
+                cursor = next;
+            }
+        })
+    }
+
+    fn try_get_status(&self, circuit_name: &str) -> Result<Option<CircuitStatus>, StorageError> {
+        let key = self.status_key(circuit_name);
+        let reply = self.with_connection(|conn| conn.command(std::vec![arg("GET"), arg(key)]))?;
+        Ok(reply
+            .into_bytes()
+            .and_then(|bytes| decode_status(core::str::from_utf8(&bytes).ok()?)))
+    }
+
+    fn try_set_status(
+        &self,
+        circuit_name: &str,
+        status: CircuitStatus,
+    ) -> Result<(), StorageError> {
+        let key = self.status_key(circuit_name);
+        self.with_connection(|conn| {
+            conn.command(std::vec![
+                arg("SET"),
+                arg(key),
+                arg(encode_status(status)),
+                arg("EX"),
+                arg(self.expires_in_secs),
+            ])
+        })
+        .map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::test_support::ManualClock;
+    use std::collections::HashMap;
+    use std::net::TcpListener;
+    use std::sync::Arc;
+
+    /// Minimal in-process RESP server supporting the commands the backend uses
+    struct FakeResp {
+        addr: String,
+        data: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+        ttls: Arc<Mutex<HashMap<String, u64>>>,
+        connections: Arc<AtomicU64>,
+    }
+
+    impl FakeResp {
+        fn start() -> Self {
+            Self::start_on(TcpListener::bind("127.0.0.1:0").unwrap(), Duration::ZERO)
+        }
+
+        /// Serve on `listener`, sleeping `delay` before each reply
+        fn start_on(listener: TcpListener, delay: Duration) -> Self {
+            let addr = listener.local_addr().unwrap().to_string();
+            let data = Arc::new(Mutex::new(HashMap::new()));
+            let ttls = Arc::new(Mutex::new(HashMap::new()));
+            let connections = Arc::new(AtomicU64::new(0));
+
+            let (server_data, server_ttls) = (Arc::clone(&data), Arc::clone(&ttls));
+            let accepted = Arc::clone(&connections);
+            std::thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let Ok(stream) = stream else { return };
+                    accepted.fetch_add(1, Ordering::SeqCst);
+                    let (data, ttls) = (Arc::clone(&server_data), Arc::clone(&server_ttls));
+                    std::thread::spawn(move || Self::serve(stream, &data, &ttls, delay));
+                }
+            });
+
+            Self {
+                addr,
+                data,
+                ttls,
+                connections,
+            }
+        }
+
+        fn serve(
+            stream: TcpStream,
+            data: &Mutex<HashMap<String, Vec<u8>>>,
+            ttls: &Mutex<HashMap<String, u64>>,
+            delay: Duration,
+        ) {
+            let mut writer = stream.try_clone().unwrap();
+            let mut reader = BufReader::new(stream);
+            while let Ok(Reply::Array(Some(args))) = read_reply(&mut reader) {
+                let args: Vec<String> = args
+                    .into_iter()
+                    .filter_map(Reply::into_bytes)
+                    .map(|b| String::from_utf8(b).unwrap())
+                    .collect();
+                let mut data = data.lock().unwrap();
+                let mut ttls = ttls.lock().unwrap();
+                let reply = match args[0].as_str() {
+                    "INCR" => {
+                        let entry = data.entry(args[1].clone()).or_insert_with(|| b"0".to_vec());
+                        let n: i64 = String::from_utf8_lossy(entry).parse().unwrap_or(0) + 1;
+                        *entry = n.to_string().into_bytes();
+                        format!(":{n}\r\n")
+                    }
+                    "EXPIRE" => {
+                        ttls.insert(args[1].clone(), args[2].parse().unwrap());
+                        ":1\r\n".to_string()
+                    }
+                    "SET" => {
+                        data.insert(args[1].clone(), args[2].clone().into_bytes());
+                        if args.len() == 5 {
+                            ttls.insert(args[1].clone(), args[4].parse().unwrap());
+                        }
+                        "+OK\r\n".to_string()
+                    }
+                    "GET" => bulk(data.get(&args[1])),
+                    // Lists are stored newline-separated
+                    "LPUSH" => {
+                        let entry = data.entry(args[1].clone()).or_default();
+                        let mut items = list_items(entry);
+                        items.insert(0, args[2].clone());
+                        *entry = items.join("\n").into_bytes();
+                        format!(":{}\r\n", items.len())
+                    }
+                    "LTRIM" => {
+                        if let Some(entry) = data.get_mut(&args[1]) {
+                            let mut items = list_items(entry);
+                            items.truncate(args[3].parse::<usize>().unwrap() + 1);
+                            *entry = items.join("\n").into_bytes();
+                        }
+                        "+OK\r\n".to_string()
+                    }
+                    "LRANGE" => {
+                        let items = data
+                            .get(&args[1])
+                            .map(|e| list_items(e))
+                            .unwrap_or_default();
+                        let stop = (args[3].parse::<usize>().unwrap() + 1).min(items.len());
+                        let mut out = format!("*{stop}\r\n");
+                        for item in &items[..stop] {
+                            out.push_str(&format!("${}\r\n{item}\r\n", item.len()));
+                        }
+                        out
+                    }
+                    "MGET" => {
+                        let mut out = format!("*{}\r\n", args.len() - 1);
+                        for key in &args[1..] {
+                            out.push_str(&bulk(data.get(key)));
+                        }
+                        out
+                    }
+                    "DEL" => {
+                        let removed = args[1..]
+                            .iter()
+                            .filter(|key| data.remove(*key).is_some())
+                            .count();
+                        format!(":{removed}\r\n")
+                    }
+                    "SCAN" => {
+                        let prefix = args[3].trim_end_matches('*');
+                        let keys: Vec<_> = data.keys().filter(|k| k.starts_with(prefix)).collect();
+                        let mut out = format!("*2\r\n$1\r\n0\r\n*{}\r\n", keys.len());
+                        for key in keys {
+                            out.push_str(&format!("${}\r\n{key}\r\n", key.len()));
+                        }
+                        out
+                    }
+                    _ => "-ERR unknown command\r\n".to_string(),
+                };
+                drop((data, ttls));
+                std::thread::sleep(delay);
+                if writer.write_all(reply.as_bytes()).is_err() {
+                    return;
+                }
+            }
+        }
+
+        fn keys(&self) -> Vec<String> {
+            let mut keys: Vec<_> = self.data.lock().unwrap().keys().cloned().collect();
+            keys.sort();
+            keys
+        }
+    }
+
+    fn list_items(entry: &[u8]) -> Vec<String> {
+        if entry.is_empty() {
+            return Vec::new();
+        }
+        String::from_utf8_lossy(entry)
+            .split('\n')
+            .map(str::to_string)
+            .collect()
+    }
+
+    fn bulk(value: Option<&Vec<u8>>) -> String {
+        match value {
+            Some(v) => format!("${}\r\n{}\r\n", v.len(), String::from_utf8_lossy(v)),
+            None => "$-1\r\n".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_resp_counts_in_window() {
+        let server = FakeResp::start();
+        let clock = ManualClock::at(1_000.0);
+        let storage = RespStorage::new(&server.addr).with_clock(Box::new(clock.clone()));
+
+        storage.record_failure("payments", 0.1);
+        clock.set(1_010.5);
+        storage.record_failure("payments", 0.1);
+        storage.record_success("payments", 0.1);
+
+        assert_eq!(storage.failure_count("payments", 60.0), 2);
+        assert_eq!(storage.failure_count("payments", 5.0), 1);
+        assert_eq!(storage.success_count("payments", 60.0), 1);
+        assert_eq!(storage.failure_count("other", 60.0), 0);
+    }
+
+    #[test]
+    fn test_resp_recent_calls_are_capped() {
+        let server = FakeResp::start();
+        let storage = RespStorage::new(&server.addr)
+            .with_max_recent_calls(3)
+            .with_clock(Box::new(ManualClock::at(1_000.0)));
+
+        storage.record_failure("payments", 0.1);
+        storage.record_failure("payments", 5.0);
+        storage.record_success("payments", 0.2);
+        storage.record_success("payments", 3.0);
+
+        let counts = storage.recent_calls("payments", 10, Some(1.0));
+        assert_eq!(counts.successes, 2);
+        assert_eq!(counts.failures, 1);
+        assert_eq!(counts.slow_calls, 2);
+        assert_eq!(storage.recent_calls("payments", 1, None).successes, 1);
+        assert_eq!(
+            storage.recent_calls("other", 10, None),
+            CallCounts::default()
+        );
+    }
+
+    #[test]
+    fn test_resp_key_scheme_and_expiration() {
+        let server = FakeResp::start();
+        let storage = RespStorage::new(&server.addr)
+            .with_prefix("app")
+            .with_expires_in_secs(120)
+            .with_clock(Box::new(ManualClock::at(42.0)));
+
+        storage.record_failure("api", 0.1);
+        storage.set_status("api", CircuitStatus::open(42.0));
+
+        assert_eq!(
+            server.keys(),
+            ["app:api:failures:42", "app:api:recent", "app:api:status"]
+        );
+        let ttls = server.ttls.lock().unwrap();
+        assert_eq!(ttls.get("app:api:failures:42"), Some(&120));
+        assert_eq!(ttls.get("app:api:recent"), Some(&120));
+        assert_eq!(ttls.get("app:api:status"), Some(&120));
+    }
+
+    #[test]
+    fn test_resp_status_round_trip_and_clear() {
+        let server = FakeResp::start();
+        let storage = RespStorage::new(&server.addr).with_clock(Box::new(ManualClock::at(50.0)));
+
+        assert_eq!(storage.get_status("api"), None);
+        storage.set_status("api", CircuitStatus::open(49.25));
+        assert_eq!(storage.get_status("api"), Some(CircuitStatus::open(49.25)));
+        let backed_off = CircuitStatus::open(49.25)
+            .with_attempt(4)
+            .with_timeout_secs(12.5);
+        storage.set_status("api", backed_off);
+        assert_eq!(storage.get_status("api"), Some(backed_off));
+        storage.set_status("api", CircuitStatus::new(CircuitState::HalfOpen));
+        assert_eq!(
+            storage.get_status("api").map(|s| s.state),
+            Some(CircuitState::HalfOpen)
+        );
+        assert_eq!(decode_status("open:3.5"), Some(CircuitStatus::open(3.5)));
+
+        storage.record_failure("api", 0.1);
+        storage.record_failure("other", 0.1);
+        storage.clear("api");
+        assert_eq!(storage.get_status("api"), None);
+        assert_eq!(storage.failure_count("api", 60.0), 0);
+        assert_eq!(storage.failure_count("other", 60.0), 1);
+
+        storage.clear_all();
+        assert!(server.keys().is_empty());
+    }
+
+    #[test]
+    fn test_resp_unreachable_server_counts_zero() {
+        // Bind then drop to get a port nothing listens on
+        let addr = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .to_string();
+        let storage = RespStorage::new(addr).with_timeout(Duration::from_millis(50));
+
+        storage.record_failure("api", 0.1);
+        assert_eq!(storage.failure_count("api", 60.0), 0);
+        assert_eq!(storage.get_status("api"), None);
+        assert!(matches!(
+            storage.try_failure_count("api", 60.0),
+            Err(StorageError::Unavailable(_))
+        ));
+        assert!(storage.try_record_failure("api", 0.1).is_err());
+    }
+
+    #[test]
+    fn test_resp_unreachable_server_fails_fast_until_cooldown_ends() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+        drop(listener);
+        let storage = RespStorage::new(&addr)
+            .with_timeout(Duration::from_millis(50))
+            .with_reconnect_cooldown(Duration::from_millis(200));
+
+        assert!(storage.try_failure_count("api", 60.0).is_err());
+
+        // The server comes back, but the cooldown still short-circuits calls
+        let server = FakeResp::start_on(TcpListener::bind(&addr).unwrap(), Duration::ZERO);
+        assert!(matches!(
+            storage.try_record_failure("api", 0.1),
+            Err(StorageError::Unavailable(_))
+        ));
+        assert_eq!(server.connections.load(Ordering::SeqCst), 0);
+
+        std::thread::sleep(Duration::from_millis(250));
+        storage.try_record_failure("api", 0.1).unwrap();
+        assert_eq!(storage.try_failure_count("api", 60.0), Ok(1));
+        assert_eq!(server.connections.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_resp_concurrent_calls_use_separate_connections() {
+        let server = FakeResp::start_on(
+            TcpListener::bind("127.0.0.1:0").unwrap(),
+            Duration::from_millis(200),
+        );
+        let storage = Arc::new(
+            RespStorage::new(&server.addr)
+                .with_timeout(Duration::from_secs(2))
+                .with_max_recent_calls(0),
+        );
+
+        let started = Instant::now();
+        let handles: Vec<_> = ["a", "b", "c", "d"]
+            .into_iter()
+            .map(|circuit| {
+                let storage = Arc::clone(&storage);
+                std::thread::spawn(move || storage.try_record_failure(circuit, 0.1))
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap().unwrap();
+        }
+
+        // Four serialized round trips would take at least 800ms
+        assert!(started.elapsed() < Duration::from_millis(600));
+        assert_eq!(server.connections.load(Ordering::SeqCst), 4);
+
+        // Idle connections are reused
+        storage.try_record_failure("a", 0.1).unwrap();
+        assert_eq!(server.connections.load(Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn test_resp_circuits_share_state_across_instances() {
+        let server = FakeResp::start();
+        let clock = ManualClock::at(100.0);
+        let host_a = Arc::new(RespStorage::new(&server.addr).with_clock(Box::new(clock.clone())));
+        let host_b = Arc::new(RespStorage::new(&server.addr).with_clock(Box::new(clock)));
+
+        let mut circuit_a = crate::CircuitBreaker::builder("payments")
+            .storage(host_a)
+            .failure_threshold(2)
+            .build();
+        let mut circuit_b = crate::CircuitBreaker::builder("payments")
+            .storage(host_b)
+            .failure_threshold(2)
+            .build();
+
+        let _ = circuit_a.call(|| Err::<(), _>("error"));
+        let _ = circuit_b.call(|| Err::<(), _>("error"));
+        assert!(circuit_b.is_open());
+
+        let result = circuit_a.call(|| Ok::<_, &str>("should not execute"));
+        assert!(matches!(result, Err(crate::CircuitError::Open { .. })));
+    }
+}
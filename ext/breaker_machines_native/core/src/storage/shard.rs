@@ -0,0 +1,83 @@
+//! Sharded circuit-name map shared by the in-memory backends
+//!
+//! Circuits are spread over a fixed number of independently locked shards and
+//! each circuit's data lives behind its own `Arc`. Recording into one circuit
+//! only touches its shard's read lock (shared) and that circuit's slot, so
+//! unrelated circuits don't serialize on a single process-wide lock. The shard
+//! write lock is only taken when a circuit is first seen or removed.
+
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::hash::BuildHasher;
+use hashbrown::{DefaultHashBuilder, HashMap};
+use spin::RwLock;
+
+/// Number of shards; a power of two so the shard index is a mask
+const SHARD_COUNT: usize = 16;
+
+pub(super) struct ShardedMap<V> {
+    shards: Vec<RwLock<HashMap<String, Arc<V>>>>,
+    hasher: DefaultHashBuilder,
+}
+
+impl<V> ShardedMap<V> {
+    pub(super) fn new() -> Self {
+        Self {
+            shards: (0..SHARD_COUNT)
+                .map(|_| RwLock::new(HashMap::new()))
+                .collect(),
+            hasher: DefaultHashBuilder::default(),
+        }
+    }
+
+    fn shard(&self, circuit_name: &str) -> &RwLock<HashMap<String, Arc<V>>> {
+        let index = self.hasher.hash_one(circuit_name) as usize & (SHARD_COUNT - 1);
+        &self.shards[index]
+    }
+
+    /// Look up a circuit's slot without creating it
+    pub(super) fn get(&self, circuit_name: &str) -> Option<Arc<V>> {
+        self.shard(circuit_name).read().get(circuit_name).cloned()
+    }
+
+    /// Look up a circuit's slot, creating it on first use.
+    ///
+    /// Only allocates the owned key when the circuit is new.
+    pub(super) fn get_or_insert_with(
+        &self,
+        circuit_name: &str,
+        init: impl FnOnce() -> V,
+    ) -> Arc<V> {
+        let shard = self.shard(circuit_name);
+        if let Some(slot) = shard.read().get(circuit_name) {
+            return Arc::clone(slot);
+        }
+
+        let mut map = shard.write();
+        Arc::clone(
+            map.entry(circuit_name.to_string())
+                .or_insert_with(|| Arc::new(init())),
+        )
+    }
+
+    pub(super) fn remove(&self, circuit_name: &str) {
+        self.shard(circuit_name).write().remove(circuit_name);
+    }
+
+    pub(super) fn clear(&self) {
+        for shard in &self.shards {
+            shard.write().clear();
+        }
+    }
+}
+
+impl<V> core::fmt::Debug for ShardedMap<V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let circuits: usize = self.shards.iter().map(|shard| shard.read().len()).sum();
+        f.debug_struct("ShardedMap")
+            .field("shards", &SHARD_COUNT)
+            .field("circuits", &circuits)
+            .finish()
+    }
+}
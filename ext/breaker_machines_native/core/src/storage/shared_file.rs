@@ -0,0 +1,961 @@
+//! Memory-mapped storage shared by every process on a host
+//!
+//! All processes that open the same file see the same per-circuit counters and
+//! circuit state, so a forked worker pool trips together instead of each worker
+//! discovering the outage on its own.
+
+use super::bucketed::{Bucket, Counter};
+use super::shard::ShardedMap;
+use super::{CallCounts, CircuitStatus, StorageBackend};
+use crate::circuit::CircuitState;
+use crate::{Event, EventKind};
+use core::cell::UnsafeCell;
+use memmap2::MmapRaw;
+use std::fs::{File, OpenOptions};
+use std::io;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::vec::Vec;
+
+/// Identifies a breaker-machines shared file ("BMSHARE1")
+const MAGIC: u64 = u64::from_le_bytes(*b"BMSHARE1");
+
+/// Bumped whenever the on-disk layout changes
+const VERSION: u64 = 4;
+
+/// Bytes reserved for the file header
+const HEADER_LEN: usize = 64;
+
+/// Longest circuit name stored verbatim; longer names are matched by prefix
+/// plus their 64-bit hash
+const MAX_NAME_LEN: usize = 104;
+
+/// Number of recent calls each slot keeps for count-based windows
+const RECENT_CALLS: usize = 128;
+
+/// Default number of circuit slots in a new file
+const DEFAULT_SLOT_COUNT: usize = 1024;
+
+/// Default number of buckets per circuit (5 minutes of 1-second buckets)
+const DEFAULT_BUCKET_COUNT: usize = 300;
+
+/// Default bucket width in seconds
+const DEFAULT_BUCKET_SECS: f64 = 1.0;
+
+/// Fixed-size header of a circuit slot; its buckets follow immediately.
+///
+/// `key` is zero while the slot is free. It is published with `Release` only
+/// after `name_len` and `name` are written, so readers that `Acquire` a
+/// non-zero key always see the complete name.
+#[repr(C)]
+struct SlotHeader {
+    key: AtomicU64,
+    /// `(opened_at_micros << 2) | state_code`, zero when nothing is stored
+    status: AtomicU64,
+    /// `(timeout_micros + 1) << 8 | attempt` for the stored status, with zero
+    /// in the upper bits when it has no timeout. Written before `status`.
+    backoff: AtomicU64,
+    name_len: UnsafeCell<u64>,
+    name: UnsafeCell<[u8; MAX_NAME_LEN]>,
+    /// Number of calls ever pushed into `recent`
+    recent_cursor: AtomicU64,
+    /// Ring of the last calls, encoded by `encode_call`
+    recent: [AtomicU64; RECENT_CALLS],
+}
+
+/// Layout parameters recorded in the file header
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Layout {
+    slot_count: usize,
+    bucket_count: usize,
+    bucket_nanos: u64,
+}
+
+impl Layout {
+    fn slot_len(&self) -> usize {
+        core::mem::size_of::<SlotHeader>() + self.bucket_count * core::mem::size_of::<Bucket>()
+    }
+
+    fn file_len(&self) -> usize {
+        HEADER_LEN + self.slot_count * self.slot_len()
+    }
+}
+
+/// Storage backend whose counters and circuit state live in a memory-mapped file
+///
+/// Every process that opens the same path shares one view: failures recorded
+/// by any worker count toward every worker's thresholds, and a circuit opened
+/// by one worker is seen as Open by the others on their next call.
+///
+/// # Time
+///
+/// [`StorageBackend::monotonic_time`] reads the host-wide monotonic clock
+/// relative to an origin stored in the file, so timestamps such as
+/// `opened_at` are comparable across processes. A file created before the
+/// last reboot is reinitialized on open; the boot is identified by the Linux
+/// boot id or, on macOS and the BSDs, by `kern.boottime`. Opening fails if the
+/// boot cannot be identified, and the feature does not build on other unix
+/// targets.
+///
+/// # Capacity
+///
+/// The file holds a fixed number of circuit slots chosen when it is created.
+/// Once every slot is taken, new circuits record nothing and always count
+/// zero. Only counters and state are shared; [`StorageBackend::event_log`]
+/// always returns an empty log, and count-based windows see at most the last
+/// 128 calls of each circuit.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use breaker_machines::{CircuitBreaker, SharedFileStorage};
+/// use std::sync::Arc;
+///
+/// let storage = Arc::new(SharedFileStorage::open("/dev/shm/my_app.breakers")?);
+/// let mut circuit = CircuitBreaker::builder("payments")
+///     .storage(storage)
+///     .failure_threshold(5)
+///     .build();
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct SharedFileStorage {
+    map: MmapRaw,
+    file: File,
+    layout: Layout,
+    /// Host monotonic reading (nanoseconds) that `monotonic_time` counts from
+    origin_nanos: u64,
+    /// Slot indexes this process has already resolved
+    slots: ShardedMap<usize>,
+    /// Serializes slot claims between threads; the file lock is a `flock`,
+    /// which only excludes other open file descriptions
+    claim_lock: Mutex<()>,
+}
+
+impl SharedFileStorage {
+    /// Open or create a shared file with 1024 circuit slots of 300 one-second buckets
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::open_with_layout(
+            path,
+            DEFAULT_SLOT_COUNT,
+            DEFAULT_BUCKET_COUNT,
+            DEFAULT_BUCKET_SECS,
+        )
+    }
+
+    /// Open or create a shared file with a custom layout
+    ///
+    /// Every process must use the same layout for a given file; opening an
+    /// existing file with a different one fails with
+    /// [`io::ErrorKind::InvalidData`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slot_count` or `bucket_count` is 0 or `bucket_secs` is not
+    /// positive.
+    pub fn open_with_layout(
+        path: impl AsRef<Path>,
+        slot_count: usize,
+        bucket_count: usize,
+        bucket_secs: f64,
+    ) -> io::Result<Self> {
+        assert!(slot_count > 0, "Slot count must be greater than 0");
+        assert!(bucket_count > 0, "Bucket count must be greater than 0");
+        assert!(bucket_secs > 0.0, "Bucket width must be positive");
+
+        let layout = Layout {
+            slot_count,
+            bucket_count,
+            bucket_nanos: ((bucket_secs * 1e9) as u64).max(1),
+        };
+
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+
+        // Serialize initialization against other processes opening the file.
+        // On error the file is dropped, which releases the lock.
+        file.lock()?;
+        let storage = Self::map_and_init(file, layout)?;
+        storage.file.unlock()?;
+        Ok(storage)
+    }
+
+    fn map_and_init(file: File, layout: Layout) -> io::Result<Self> {
+        let file_len = layout.file_len() as u64;
+        let existing_len = file.metadata()?.len();
+        if existing_len != 0 && existing_len != file_len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "shared circuit file has a different layout",
+            ));
+        }
+        if existing_len == 0 {
+            file.set_len(file_len)?;
+        }
+
+        let map = MmapRaw::map_raw(&file)?;
+        let now = host_monotonic_nanos();
+        let boot = boot_marker()?;
+
+        let header = read_header(&map);
+        let origin_nanos = match header {
+            Some(stored) if stored.layout != layout => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "shared circuit file has a different layout",
+                ));
+            }
+            Some(stored) if stored.boot == boot && stored.origin_nanos <= now => {
+                stored.origin_nanos
+            }
+            // New file, or one left over from before a reboot: start fresh
+            _ => {
+                // SAFETY: the file lock is held, so no other process is
+                // reading or writing the mapping during initialization, and
+                // the mapping is exactly `file_len` bytes long.
+                unsafe { core::ptr::write_bytes(map.as_mut_ptr(), 0, layout.file_len()) };
+                write_header(&map, layout, now, boot);
+                map.flush()?;
+                now
+            }
+        };
+
+        Ok(Self {
+            map,
+            file,
+            layout,
+            origin_nanos,
+            slots: ShardedMap::new(),
+            claim_lock: Mutex::new(()),
+        })
+    }
+
+    /// Number of circuit slots in the file
+    pub fn slot_count(&self) -> usize {
+        self.layout.slot_count
+    }
+
+    // Private helper methods
+
+    fn slot_header(&self, index: usize) -> &SlotHeader {
+        debug_assert!(index < self.layout.slot_count);
+        // SAFETY: `index` is in bounds, slots start 8-byte aligned after the
+        // 64-byte header and every slot length is a multiple of 8, and the
+        // mapping lives as long as `self`. All fields mutated after
+        // publication are atomics.
+        unsafe {
+            &*(self
+                .map
+                .as_ptr()
+                .add(HEADER_LEN + index * self.layout.slot_len())
+                .cast::<SlotHeader>())
+        }
+    }
+
+    fn slot_buckets(&self, index: usize) -> &[Bucket] {
+        debug_assert!(index < self.layout.slot_count);
+        // SAFETY: as in `slot_header`; the buckets directly follow the slot
+        // header and consist only of atomics.
+        unsafe {
+            let start = self
+                .map
+                .as_ptr()
+                .add(HEADER_LEN + index * self.layout.slot_len())
+                .add(core::mem::size_of::<SlotHeader>())
+                .cast::<Bucket>();
+            core::slice::from_raw_parts(start, self.layout.bucket_count)
+        }
+    }
+
+    fn slot_matches(&self, index: usize, key: u64, circuit_name: &str) -> bool {
+        let slot = self.slot_header(index);
+        if slot.key.load(Ordering::Acquire) != key {
+            return false;
+        }
+        // SAFETY: the key was published with `Release` after the name was
+        // written, and a published name is never written again.
+        let (name_len, name) = unsafe { (*slot.name_len.get(), &*slot.name.get()) };
+        &name[..(name_len as usize).min(MAX_NAME_LEN)] == stored_name(circuit_name)
+    }
+
+    /// Probe the table for a circuit; `None` if it has never been recorded
+    fn find_slot(&self, circuit_name: &str) -> Option<usize> {
+        if let Some(index) = self.slots.get(circuit_name) {
+            return Some(*index);
+        }
+
+        let key = slot_key(circuit_name);
+        let start = (key % self.layout.slot_count as u64) as usize;
+        for probe in 0..self.layout.slot_count {
+            let index = (start + probe) % self.layout.slot_count;
+            let slot_key = self.slot_header(index).key.load(Ordering::Acquire);
+            if slot_key == 0 {
+                return None;
+            }
+            if self.slot_matches(index, key, circuit_name) {
+                self.slots.get_or_insert_with(circuit_name, || index);
+                return Some(index);
+            }
+        }
+        None
+    }
+
+    /// Find a circuit's slot, claiming a free one if needed
+    fn find_or_claim_slot(&self, circuit_name: &str) -> Option<usize> {
+        if let Some(index) = self.find_slot(circuit_name) {
+            return Some(index);
+        }
+
+        // Claiming is rare (once per circuit per file), so take the locks and
+        // re-probe instead of coordinating claims lock-free. The mutex keeps
+        // out this process's other threads, which share the file handle.
+        let _claiming = self
+            .claim_lock
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        self.file.lock().ok()?;
+        let claimed = self.claim_slot_locked(circuit_name);
+        let _ = self.file.unlock();
+
+        if let Some(index) = claimed {
+            self.slots.get_or_insert_with(circuit_name, || index);
+        }
+        claimed
+    }
+
+    fn claim_slot_locked(&self, circuit_name: &str) -> Option<usize> {
+        let key = slot_key(circuit_name);
+        let start = (key % self.layout.slot_count as u64) as usize;
+        for probe in 0..self.layout.slot_count {
+            let index = (start + probe) % self.layout.slot_count;
+            if self.slot_matches(index, key, circuit_name) {
+                return Some(index);
+            }
+
+            let slot = self.slot_header(index);
+            if slot.key.load(Ordering::Acquire) == 0 {
+                let name = stored_name(circuit_name);
+                // SAFETY: the slot is unpublished (key == 0) and both the
+                // claim mutex and the file lock are held, so nobody else
+                // writes or reads its name fields.
+                unsafe {
+                    *slot.name_len.get() = name.len() as u64;
+                    (&mut *slot.name.get())[..name.len()].copy_from_slice(name);
+                }
+                slot.key.store(key, Ordering::Release);
+                return Some(index);
+            }
+        }
+        None
+    }
+
+    fn reset_slot(&self, index: usize) {
+        let slot = self.slot_header(index);
+        slot.status.store(0, Ordering::Release);
+        slot.backoff.store(0, Ordering::Release);
+        slot.recent_cursor.store(0, Ordering::Release);
+        for entry in &slot.recent {
+            entry.store(0, Ordering::Release);
+        }
+        for bucket in self.slot_buckets(index) {
+            bucket.counter(Counter::Success).reset();
+            bucket.counter(Counter::Failure).reset();
+            bucket.counter(Counter::Slow).reset();
+        }
+    }
+
+    fn current_epoch(&self) -> u64 {
+        self.elapsed_nanos() / self.layout.bucket_nanos
+    }
+
+    fn elapsed_nanos(&self) -> u64 {
+        host_monotonic_nanos().saturating_sub(self.origin_nanos)
+    }
+
+    fn record_event(&self, circuit_name: &str, counter: Counter) -> Option<usize> {
+        let index = self.find_or_claim_slot(circuit_name)?;
+        let epoch = self.current_epoch();
+        let buckets = self.slot_buckets(index);
+        buckets[(epoch % self.layout.bucket_count as u64) as usize]
+            .counter(counter)
+            .increment(epoch);
+        Some(index)
+    }
+
+    fn record_call(&self, circuit_name: &str, kind: EventKind, duration: f64) {
+        let Some(index) = self.record_event(circuit_name, kind.into()) else {
+            return;
+        };
+        let slot = self.slot_header(index);
+        let position = slot.recent_cursor.fetch_add(1, Ordering::AcqRel);
+        slot.recent[(position % RECENT_CALLS as u64) as usize]
+            .store(encode_call(kind, duration), Ordering::Release);
+    }
+
+    fn count_events(&self, circuit_name: &str, counter: Counter, window_seconds: f64) -> usize {
+        let Some(index) = self.find_slot(circuit_name) else {
+            return 0;
+        };
+
+        let bucket_secs = self.layout.bucket_nanos as f64 / 1e9;
+        let span = ((window_seconds / bucket_secs).ceil() as u64)
+            .clamp(1, self.layout.bucket_count as u64);
+        let current = self.current_epoch();
+        let buckets = self.slot_buckets(index);
+
+        (0..span)
+            .map_while(|offset| current.checked_sub(offset))
+            .map(|epoch| {
+                buckets[(epoch % self.layout.bucket_count as u64) as usize]
+                    .counter(counter)
+                    .count(epoch)
+            })
+            .sum()
+    }
+}
+
+impl core::fmt::Debug for SharedFileStorage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SharedFileStorage")
+            .field("layout", &self.layout)
+            .field("origin_nanos", &self.origin_nanos)
+            .finish()
+    }
+}
+
+impl StorageBackend for SharedFileStorage {
+    fn record_success(&self, circuit_name: &str, duration: f64) {
+        self.record_call(circuit_name, EventKind::Success, duration);
+    }
+
+    fn record_failure(&self, circuit_name: &str, duration: f64) {
+        self.record_call(circuit_name, EventKind::Failure, duration);
+    }
+
+    fn success_count(&self, circuit_name: &str, window_seconds: f64) -> usize {
+        self.count_events(circuit_name, Counter::Success, window_seconds)
+    }
+
+    fn failure_count(&self, circuit_name: &str, window_seconds: f64) -> usize {
+        self.count_events(circuit_name, Counter::Failure, window_seconds)
+    }
+
+    fn record_slow_call(&self, circuit_name: &str, _duration: f64) {
+        self.record_event(circuit_name, Counter::Slow);
+    }
+
+    fn recent_calls(
+        &self,
+        circuit_name: &str,
+        calls: usize,
+        slow_call_secs: Option<f64>,
+    ) -> CallCounts {
+        let mut counts = CallCounts::default();
+        let Some(index) = self.find_slot(circuit_name) else {
+            return counts;
+        };
+
+        let slot = self.slot_header(index);
+        let end = slot.recent_cursor.load(Ordering::Acquire);
+        let len = end.min(calls.min(RECENT_CALLS) as u64);
+        for position in (end - len..end).rev() {
+            let raw =
+                slot.recent[(position % RECENT_CALLS as u64) as usize].load(Ordering::Acquire);
+            if let Some((kind, duration)) = decode_call(raw) {
+                counts.add(kind, duration, slow_call_secs);
+            }
+        }
+        counts
+    }
+
+    fn slow_call_count(&self, circuit_name: &str, window_seconds: f64) -> usize {
+        self.count_events(circuit_name, Counter::Slow, window_seconds)
+    }
+
+    fn clear(&self, circuit_name: &str) {
+        if let Some(index) = self.find_slot(circuit_name) {
+            self.reset_slot(index);
+        }
+    }
+
+    fn clear_all(&self) {
+        for index in 0..self.layout.slot_count {
+            if self.slot_header(index).key.load(Ordering::Acquire) != 0 {
+                self.reset_slot(index);
+            }
+        }
+    }
+
+    fn event_log(&self, _circuit_name: &str, _limit: usize) -> Vec<Event> {
+        Vec::new()
+    }
+
+    fn monotonic_time(&self) -> f64 {
+        self.elapsed_nanos() as f64 / 1e9
+    }
+
+    fn get_status(&self, circuit_name: &str) -> Option<CircuitStatus> {
+        let index = self.find_slot(circuit_name)?;
+        let slot = self.slot_header(index);
+        // `backoff` is written first, so an unchanged `status` around the
+        // read means both words belong to the same write
+        loop {
+            let status = slot.status.load(Ordering::Acquire);
+            let backoff = slot.backoff.load(Ordering::Acquire);
+            if slot.status.load(Ordering::Acquire) == status {
+                return decode_status(status, backoff);
+            }
+        }
+    }
+
+    fn set_status(&self, circuit_name: &str, status: CircuitStatus) {
+        if let Some(index) = self.find_or_claim_slot(circuit_name) {
+            let slot = self.slot_header(index);
+            slot.backoff
+                .store(encode_backoff(status), Ordering::Release);
+            slot.status.store(encode_status(status), Ordering::Release);
+        }
+    }
+
+    fn shares_state(&self) -> bool {
+        true
+    }
+}
+
+/// Header fields read back from an initialized file
+struct Header {
+    layout: Layout,
+    origin_nanos: u64,
+    boot: u64,
+}
+
+fn header_bytes(map: &MmapRaw) -> &[u8] {
+    // SAFETY: the mapping is at least HEADER_LEN bytes and the header is only
+    // written during initialization, under the file lock.
+    unsafe { core::slice::from_raw_parts(map.as_ptr(), HEADER_LEN) }
+}
+
+fn read_header(map: &MmapRaw) -> Option<Header> {
+    let bytes = header_bytes(map);
+    let word = |i: usize| {
+        let mut raw = [0u8; 8];
+        raw.copy_from_slice(&bytes[i * 8..i * 8 + 8]);
+        u64::from_le_bytes(raw)
+    };
+
+    if word(0) != MAGIC || word(1) != VERSION {
+        return None;
+    }
+    Some(Header {
+        layout: Layout {
+            slot_count: word(2) as usize,
+            bucket_count: word(3) as usize,
+            bucket_nanos: word(4),
+        },
+        origin_nanos: word(5),
+        boot: word(6),
+    })
+}
+
+fn write_header(map: &MmapRaw, layout: Layout, origin_nanos: u64, boot: u64) {
+    let words = [
+        MAGIC,
+        VERSION,
+        layout.slot_count as u64,
+        layout.bucket_count as u64,
+        layout.bucket_nanos,
+        origin_nanos,
+        boot,
+    ];
+    for (i, word) in words.iter().enumerate() {
+        let bytes = word.to_le_bytes();
+        // SAFETY: called only during initialization under the file lock; the
+        // seven words fit inside the 64-byte header.
+        unsafe {
+            core::ptr::copy_nonoverlapping(bytes.as_ptr(), map.as_mut_ptr().add(i * 8), 8);
+        }
+    }
+}
+
+/// Bytes of the name kept in the slot
+fn stored_name(circuit_name: &str) -> &[u8] {
+    let bytes = circuit_name.as_bytes();
+    &bytes[..bytes.len().min(MAX_NAME_LEN)]
+}
+
+/// Stable FNV-1a hash of the full name; never zero, since zero marks a free slot.
+///
+/// This must be identical in every process, so it can't use a seeded hasher.
+fn slot_key(circuit_name: &str) -> u64 {
+    let hash = circuit_name
+        .as_bytes()
+        .iter()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+        });
+    hash.max(1)
+}
+
+fn encode_status(status: CircuitStatus) -> u64 {
+    let code = match status.state {
+        CircuitState::Closed => 1,
+        CircuitState::Open => 2,
+        CircuitState::HalfOpen => 3,
+    };
+    let opened_micros = status
+        .opened_at
+        .map(|secs| (secs.max(0.0) * 1e6) as u64)
+        .unwrap_or(0);
+    (opened_micros << 2) | code
+}
+
+fn encode_backoff(status: CircuitStatus) -> u64 {
+    let timeout = status
+        .timeout_secs
+        .map(|secs| (secs.max(0.0) * 1e6) as u64 + 1)
+        .unwrap_or(0);
+    (timeout << 8) | u64::from(status.attempt.min(u32::from(u8::MAX)))
+}
+
+fn decode_status(raw: u64, backoff: u64) -> Option<CircuitStatus> {
+    let state = match raw & 0b11 {
+        1 => CircuitState::Closed,
+        2 => CircuitState::Open,
+        3 => CircuitState::HalfOpen,
+        _ => return None,
+    };
+    let mut status = match state {
+        CircuitState::Open => CircuitStatus::open((raw >> 2) as f64 / 1e6),
+        state => CircuitStatus::new(state),
+    };
+    status.attempt = (backoff & 0xff) as u32;
+    if let Some(timeout) = (backoff >> 8).checked_sub(1) {
+        status.timeout_secs = Some(timeout as f64 / 1e6);
+    }
+    Some(status)
+}
+
+/// `(duration_f32_bits << 32) | kind_code`, zero for an empty entry
+fn encode_call(kind: EventKind, duration: f64) -> u64 {
+    let code = match kind {
+        EventKind::Success => 1,
+        EventKind::Failure => 2,
+    };
+    (u64::from((duration as f32).to_bits()) << 32) | code
+}
+
+fn decode_call(raw: u64) -> Option<(EventKind, f64)> {
+    let kind = match raw & 0b11 {
+        1 => EventKind::Success,
+        2 => EventKind::Failure,
+        _ => return None,
+    };
+    Some((kind, f64::from(f32::from_bits((raw >> 32) as u32))))
+}
+
+/// Host-wide monotonic clock in nanoseconds.
+///
+/// Unlike `Instant`, the raw reading is shared by every process on the host,
+/// which is what makes timestamps in the file comparable.
+fn host_monotonic_nanos() -> u64 {
+    let mut ts = libc::timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    // SAFETY: `ts` is a valid, writable timespec and CLOCK_MONOTONIC is
+    // supported on every unix target.
+    unsafe { libc::clock_gettime(libc::CLOCK_MONOTONIC, &mut ts) };
+    (ts.tv_sec as u64)
+        .saturating_mul(1_000_000_000)
+        .saturating_add(ts.tv_nsec as u64)
+}
+
+/// Identifies the current boot so files from before a reboot, whose monotonic
+/// origin is meaningless now, get reinitialized.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn boot_marker() -> io::Result<u64> {
+    let id = std::fs::read_to_string("/proc/sys/kernel/random/boot_id")?;
+    Ok(slot_key(id.trim()))
+}
+
+/// Identifies the current boot by its `kern.boottime`, so files from before a
+/// reboot, whose monotonic origin is meaningless now, get reinitialized.
+///
+/// The kernel shifts the boot time when the wall clock is stepped, which
+/// reinitializes the file too early but never reuses a stale origin.
+#[cfg(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+fn boot_marker() -> io::Result<u64> {
+    let mut mib = [libc::CTL_KERN, libc::KERN_BOOTTIME];
+    let mut boot_time = libc::timeval {
+        tv_sec: 0,
+        tv_usec: 0,
+    };
+    let mut len = core::mem::size_of::<libc::timeval>();
+    // SAFETY: `mib` names a read-only sysctl whose value is a `timeval`, and
+    // `boot_time`/`len` describe a writable buffer of exactly that size.
+    let rc = unsafe {
+        libc::sysctl(
+            mib.as_mut_ptr(),
+            mib.len() as libc::c_uint,
+            (&mut boot_time as *mut libc::timeval).cast(),
+            &mut len,
+            core::ptr::null_mut(),
+            0,
+        )
+    };
+    if rc != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok((boot_time.tv_sec as u64)
+        .wrapping_mul(1_000_000)
+        .wrapping_add(boot_time.tv_usec as u64))
+}
+
+// Without a boot identifier a file from before a reboot would be trusted and
+// its monotonic timestamps compared against a clock that restarted from zero.
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd"
+)))]
+compile_error!(
+    "the `shared-file` feature needs a boot identifier, which is only implemented \
+     for Linux, Android, macOS, iOS and the BSDs"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicUsize;
+
+    /// Unique temp path removed when dropped
+    struct TempPath(PathBuf);
+
+    impl TempPath {
+        fn new() -> Self {
+            static COUNTER: AtomicUsize = AtomicUsize::new(0);
+            let n = COUNTER.fetch_add(1, Ordering::SeqCst);
+            Self(std::env::temp_dir().join(std::format!(
+                "breaker_machines_{}_{n}.shared",
+                std::process::id()
+            )))
+        }
+    }
+
+    impl Drop for TempPath {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    fn open(path: &TempPath) -> SharedFileStorage {
+        SharedFileStorage::open_with_layout(&path.0, 16, 60, 1.0).unwrap()
+    }
+
+    fn open_with(path: &TempPath, slot_count: usize) -> SharedFileStorage {
+        SharedFileStorage::open_with_layout(&path.0, slot_count, 10, 1.0).unwrap()
+    }
+
+    #[test]
+    fn test_shared_file_counts_across_mappings() {
+        let path = TempPath::new();
+        let worker_a = open(&path);
+        let worker_b = open(&path);
+
+        worker_a.record_failure("payments", 0.1);
+        worker_b.record_failure("payments", 0.1);
+        worker_b.record_success("payments", 0.1);
+
+        assert_eq!(worker_a.failure_count("payments", 60.0), 2);
+        assert_eq!(worker_b.failure_count("payments", 60.0), 2);
+        assert_eq!(worker_a.success_count("payments", 60.0), 1);
+        assert_eq!(worker_a.failure_count("unknown", 60.0), 0);
+    }
+
+    #[test]
+    fn test_shared_file_recent_calls_across_mappings() {
+        let path = TempPath::new();
+        let worker_a = open(&path);
+        let worker_b = open(&path);
+
+        for _ in 0..RECENT_CALLS {
+            worker_a.record_failure("payments", 0.1);
+        }
+        worker_b.record_success("payments", 2.0);
+        worker_b.record_failure("payments", 0.5);
+
+        let counts = worker_a.recent_calls("payments", 3, Some(1.0));
+        assert_eq!(counts.successes, 1);
+        assert_eq!(counts.failures, 2);
+        assert_eq!(counts.slow_calls, 1);
+        // The ring keeps only the last RECENT_CALLS calls
+        assert_eq!(
+            worker_b.recent_calls("payments", 1000, None).total(),
+            RECENT_CALLS
+        );
+
+        worker_b.clear("payments");
+        assert_eq!(
+            worker_a.recent_calls("payments", 10, None),
+            CallCounts::default()
+        );
+    }
+
+    #[test]
+    fn test_shared_file_time_is_comparable_across_mappings() {
+        let path = TempPath::new();
+        let worker_a = open(&path);
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        let worker_b = open(&path);
+
+        let a = worker_a.monotonic_time();
+        let b = worker_b.monotonic_time();
+        assert!(
+            (a - b).abs() < 0.01,
+            "both mappings share one origin: {a} vs {b}"
+        );
+        assert!(b >= 0.02);
+    }
+
+    #[test]
+    fn test_shared_file_status_round_trip() {
+        let path = TempPath::new();
+        let worker_a = open(&path);
+        let worker_b = open(&path);
+
+        assert_eq!(worker_b.get_status("payments"), None);
+        worker_a.set_status(
+            "payments",
+            CircuitStatus::open(1.5)
+                .with_attempt(3)
+                .with_timeout_secs(7.25),
+        );
+        assert_eq!(
+            worker_b.get_status("payments"),
+            Some(
+                CircuitStatus::open(1.5)
+                    .with_attempt(3)
+                    .with_timeout_secs(7.25)
+            )
+        );
+
+        worker_b.clear("payments");
+        assert_eq!(worker_a.get_status("payments"), None);
+    }
+
+    #[test]
+    fn test_shared_file_rejects_layout_mismatch() {
+        let path = TempPath::new();
+        let _existing = open(&path);
+
+        let err = SharedFileStorage::open_with_layout(&path.0, 32, 60, 1.0).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_shared_file_full_table_records_nothing() {
+        let path = TempPath::new();
+        let storage = SharedFileStorage::open_with_layout(&path.0, 2, 10, 1.0).unwrap();
+
+        storage.record_failure("a", 0.1);
+        storage.record_failure("b", 0.1);
+        storage.record_failure("c", 0.1);
+
+        assert_eq!(storage.failure_count("a", 10.0), 1);
+        assert_eq!(storage.failure_count("b", 10.0), 1);
+        assert_eq!(storage.failure_count("c", 10.0), 0);
+    }
+
+    #[test]
+    fn test_shared_file_concurrent_claims_get_distinct_slots() {
+        const THREADS: usize = 8;
+        const CIRCUITS: usize = 8;
+
+        // Exactly one slot per circuit, so racing claims have to probe past
+        // each other's slots
+        let path = TempPath::new();
+        let storage = Arc::new(open_with(&path, THREADS * CIRCUITS));
+        let start = Arc::new(std::sync::Barrier::new(THREADS));
+
+        let workers: Vec<_> = (0..THREADS)
+            .map(|worker| {
+                let storage = Arc::clone(&storage);
+                let start = Arc::clone(&start);
+                std::thread::spawn(move || {
+                    start.wait();
+                    for circuit in 0..CIRCUITS {
+                        let name = std::format!("circuit-{worker}-{circuit}");
+                        storage.record_failure(&name, 0.1);
+                    }
+                })
+            })
+            .collect();
+        for worker in workers {
+            worker.join().unwrap();
+        }
+
+        let reopened = open_with(&path, THREADS * CIRCUITS);
+        for worker in 0..THREADS {
+            for circuit in 0..CIRCUITS {
+                let name = std::format!("circuit-{worker}-{circuit}");
+                assert_eq!(storage.failure_count(&name, 10.0), 1, "{name}");
+                assert_eq!(reopened.failure_count(&name, 10.0), 1, "{name}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_shared_file_long_names() {
+        let path = TempPath::new();
+        let storage = open(&path);
+        let long_a = "x".repeat(200) + "a";
+        let long_b = "x".repeat(200) + "b";
+
+        storage.record_failure(&long_a, 0.1);
+        assert_eq!(storage.failure_count(&long_a, 10.0), 1);
+        assert_eq!(storage.failure_count(&long_b, 10.0), 0);
+    }
+
+    #[test]
+    fn test_shared_file_circuits_trip_together() {
+        let path = TempPath::new();
+        let storage_a = Arc::new(open(&path));
+        let storage_b = Arc::new(open(&path));
+
+        let mut worker_a = crate::CircuitBreaker::builder("payments")
+            .storage(storage_a)
+            .failure_threshold(2)
+            .build();
+        let mut worker_b = crate::CircuitBreaker::builder("payments")
+            .storage(storage_b)
+            .failure_threshold(2)
+            .build();
+
+        let _ = worker_a.call(|| Err::<(), _>("error"));
+        let _ = worker_b.call(|| Err::<(), _>("error"));
+        assert!(worker_b.is_open(), "combined failures reach the threshold");
+
+        // Worker A never saw the second failure but adopts the shared state
+        let result = worker_a.call(|| Ok::<_, &str>("should not execute"));
+        assert!(matches!(result, Err(crate::CircuitError::Open { .. })));
+        assert!(worker_a.is_open());
+    }
+}
@@ -51,6 +51,44 @@ impl Clock for SystemClock {
     }
 }
 
+/// Test fixtures shared across the crate's unit tests.
+#[cfg(test)]
+pub(crate) mod test_support {
+    use super::Clock;
+    use alloc::sync::Arc;
+    use core::sync::atomic::{AtomicU64, Ordering};
+
+    /// Clock advanced by hand so time-dependent behavior is deterministic.
+    ///
+    /// Clones share the same reading, so a test can keep one handle and pass
+    /// another into the storage or circuit under test.
+    #[derive(Debug, Clone, Default)]
+    pub(crate) struct ManualClock(Arc<AtomicU64>);
+
+    // Not every helper is used under every feature combination.
+    #[allow(dead_code)]
+    impl ManualClock {
+        /// A clock that starts at `secs`.
+        pub(crate) fn at(secs: f64) -> Self {
+            Self(Arc::new(AtomicU64::new(secs.to_bits())))
+        }
+
+        pub(crate) fn set(&self, secs: f64) {
+            self.0.store(secs.to_bits(), Ordering::SeqCst);
+        }
+
+        pub(crate) fn advance(&self, secs: f64) {
+            self.set(self.now_secs() + secs);
+        }
+    }
+
+    impl Clock for ManualClock {
+        fn now_secs(&self) -> f64 {
+            f64::from_bits(self.0.load(Ordering::SeqCst))
+        }
+    }
+}
+
 #[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
@@ -0,0 +1,245 @@
+//! Runtime-agnostic timer for async call timeouts
+//!
+//! One background thread wakes every pending [`Sleep`], so timeouts work
+//! under any executor without depending on a runtime's timer.
+
+use crate::errors::CallTimeout;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, OnceLock, PoisonError};
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Default)]
+struct Alarm {
+    fired: AtomicBool,
+    waker: Mutex<Option<Waker>>,
+}
+
+impl Alarm {
+    fn fire(&self) {
+        self.fired.store(true, Ordering::Release);
+        let waker = self
+            .waker
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take();
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+/// Pending alarms keyed by deadline, then by a per-sleep id for uniqueness
+type Queue = BTreeMap<(Instant, u64), Arc<Alarm>>;
+
+#[derive(Debug, Default)]
+struct Timer {
+    queue: Mutex<Queue>,
+    changed: Condvar,
+    next_id: AtomicU64,
+}
+
+impl Timer {
+    fn get() -> &'static Timer {
+        static TIMER: OnceLock<Timer> = OnceLock::new();
+        static THREAD: OnceLock<()> = OnceLock::new();
+
+        let timer = TIMER.get_or_init(Timer::default);
+        THREAD.get_or_init(|| {
+            // Failing to start one thread means the process is out of threads
+            // or memory; `std::thread::spawn` panics in the same situation,
+            // and without the thread no timeout could ever fire.
+            #[allow(clippy::expect_used)]
+            let worker = std::thread::Builder::new()
+                .name("breaker-machines-timer".into())
+                .spawn(|| timer.run())
+                .expect("failed to spawn timer thread");
+            drop(worker);
+        });
+        timer
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Queue> {
+        self.queue.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Queue `alarm` to fire at `deadline`, returning its key for [`cancel`](Self::cancel)
+    fn schedule(&self, deadline: Instant, alarm: &Arc<Alarm>) -> (Instant, u64) {
+        let key = (deadline, self.next_id.fetch_add(1, Ordering::Relaxed));
+        self.lock().insert(key, Arc::clone(alarm));
+        self.changed.notify_one();
+        key
+    }
+
+    /// Remove an alarm that is no longer awaited
+    fn cancel(&self, key: (Instant, u64)) {
+        self.lock().remove(&key);
+    }
+
+    fn run(&self) {
+        let mut queue = self.lock();
+        loop {
+            let now = Instant::now();
+            while let Some(entry) = queue.first_entry()
+                && entry.key().0 <= now
+            {
+                entry.remove().fire();
+            }
+
+            queue = match queue.first_key_value() {
+                Some(((deadline, _), _)) => {
+                    let wait = deadline.saturating_duration_since(now);
+                    self.changed
+                        .wait_timeout(queue, wait)
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .0
+                }
+                None => self
+                    .changed
+                    .wait(queue)
+                    .unwrap_or_else(PoisonError::into_inner),
+            };
+        }
+    }
+}
+
+/// Future that completes once `duration` has passed
+///
+/// Dropping a pending sleep takes its alarm off the timer's queue.
+#[derive(Debug)]
+pub(crate) struct Sleep {
+    /// None when the deadline is too far out to represent; never completes
+    deadline: Option<Instant>,
+    /// Alarm and its queue key, once the sleep has been polled
+    alarm: Option<(Arc<Alarm>, (Instant, u64))>,
+}
+
+impl Sleep {
+    pub(crate) fn new(duration: Duration) -> Self {
+        Self {
+            deadline: Instant::now().checked_add(duration),
+            alarm: None,
+        }
+    }
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = self.get_mut();
+        let Some(deadline) = this.deadline else {
+            return Poll::Pending;
+        };
+        if Instant::now() >= deadline {
+            return Poll::Ready(());
+        }
+
+        let (alarm, _) = this.alarm.get_or_insert_with(|| {
+            let alarm = Arc::new(Alarm::default());
+            let key = Timer::get().schedule(deadline, &alarm);
+            (alarm, key)
+        });
+        *alarm.waker.lock().unwrap_or_else(PoisonError::into_inner) = Some(cx.waker().clone());
+        // The alarm may have fired before the waker was stored
+        if alarm.fired.load(Ordering::Acquire) {
+            return Poll::Ready(());
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for Sleep {
+    fn drop(&mut self) {
+        if let Some((alarm, key)) = self.alarm.take()
+            && !alarm.fired.load(Ordering::Acquire)
+        {
+            Timer::get().cancel(key);
+        }
+    }
+}
+
+/// Run `future`, giving up after `timeout_secs` if set
+pub(crate) async fn within<F: Future>(
+    timeout_secs: Option<f64>,
+    future: F,
+) -> Result<F::Output, CallTimeout> {
+    let Some(timeout_secs) = timeout_secs else {
+        return Ok(future.await);
+    };
+
+    let mut future = core::pin::pin!(future);
+    let mut sleep = Sleep::new(Duration::try_from_secs_f64(timeout_secs).unwrap_or(Duration::MAX));
+    core::future::poll_fn(|cx| {
+        if let Poll::Ready(output) = future.as_mut().poll(cx) {
+            return Poll::Ready(Ok(output));
+        }
+        Pin::new(&mut sleep)
+            .poll(cx)
+            .map(|()| Err(CallTimeout { timeout_secs }))
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn within_times_out_pending_futures() {
+        let result = pollster::block_on(within(Some(0.01), std::future::pending::<()>()));
+        assert_eq!(result, Err(CallTimeout { timeout_secs: 0.01 }));
+
+        let result = pollster::block_on(within(Some(60.0), async { "done" }));
+        assert_eq!(result, Ok("done"));
+    }
+
+    #[test]
+    fn sleeps_wake_in_deadline_order() {
+        // Deadlines far apart, so a stalled machine can't make them coincide
+        let mut long = Box::pin(Sleep::new(Duration::from_millis(500)));
+        let mut short = Box::pin(Sleep::new(Duration::from_millis(10)));
+        let mut woken = Vec::new();
+
+        pollster::block_on(core::future::poll_fn(|cx| {
+            if !woken.contains(&"long") && long.as_mut().poll(cx).is_ready() {
+                woken.push("long");
+            }
+            if !woken.contains(&"short") && short.as_mut().poll(cx).is_ready() {
+                woken.push("short");
+            }
+            if woken.len() == 2 {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            }
+        }));
+        assert_eq!(woken, ["short", "long"]);
+    }
+
+    #[test]
+    fn dropped_sleeps_leave_the_queue() {
+        let mut sleeps: Vec<_> = (0..100)
+            .map(|_| Box::pin(Sleep::new(Duration::from_secs(3600))))
+            .collect();
+        for sleep in &mut sleeps {
+            assert!(poll_once(sleep.as_mut()).is_pending());
+        }
+        let keys: Vec<_> = sleeps
+            .iter()
+            .filter_map(|sleep| sleep.alarm.as_ref().map(|(_, key)| *key))
+            .collect();
+        assert_eq!(keys.len(), 100);
+        assert!(keys.iter().all(|key| Timer::get().lock().contains_key(key)));
+
+        drop(sleeps);
+        assert!(!keys.iter().any(|key| Timer::get().lock().contains_key(key)));
+    }
+
+    fn poll_once<F: Future + ?Sized>(future: Pin<&mut F>) -> Poll<F::Output> {
+        future.poll(&mut Context::from_waker(Waker::noop()))
+    }
+}
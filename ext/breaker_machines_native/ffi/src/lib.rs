@@ -139,6 +139,7 @@ impl RubyCircuit {
             half_open_timeout_secs,
             success_threshold,
             jitter_factor,
+            ..Config::default()
         };
 
         Ok(Self {